@@ -3,14 +3,50 @@
 //! Manages the TUI application state, navigation, and operations.
 
 use anyhow::Result;
+use crossterm::event::{KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use fuzzy_matcher::skim::SkimMatcherV2;
-use fuzzy_matcher::FuzzyMatcher;
-use std::collections::HashMap;
-
-use crate::config::{templates::Template, Settings};
-use crate::event::{key_to_action, Action, AppEvent, EventConfig, EventHandler, Terminal};
-use crate::screen::{self, Preview, Session, Window};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+use crate::config::snapshot::{self, Snapshot};
+use crate::config::{
+    history::History, input_history::InputHistory, project::Project, templates::Template, Settings,
+};
+use crate::event::{
+    copy_mode_action, key_to_action, Action, AppEvent, EventConfig, EventHandler, Terminal,
+};
+use crate::screen::broadcast::{self, BroadcastTarget};
+use crate::screen::bulk::{self, BulkOp, BulkResult, Target};
+use crate::screen::dashboard::Tile;
+use crate::screen::grep::GrepHit;
+use crate::screen::scrollback::ScrollbackSearch;
+use crate::screen::selection::{CopyMode, Motion};
+use crate::screen::window::WindowOp;
+use crate::screen::{self, Filter, GroupBy, Preview, Session, SessionGroup, SortKey, Window};
 use crate::ui;
+use crate::ui::hitmap::{HitMap, HitTarget};
+
+/// How much frecency contributes to fuzzy search ranking
+const FRECENCY_WEIGHT: f64 = 10.0;
+
+/// Maximum delay between the clicks of a double-click
+const DOUBLE_CLICK: Duration = Duration::from_millis(400);
+
+/// Lines scrolled per mouse wheel step in the preview
+const WHEEL_LINES: usize = 3;
+
+/// Lines of context kept around each grep hit
+const GREP_CONTEXT: usize = 3;
+
+/// Pane that receives navigation keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Focus {
+    /// The session, window or template list
+    #[default]
+    List,
+    /// The preview pane
+    Preview,
+}
 
 /// Current view/mode of the application
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -25,6 +61,10 @@ pub enum View {
     Help,
     /// Settings/configuration
     Settings,
+    /// Grid of live previews
+    Dashboard,
+    /// Scrollback matches across sessions
+    Grep,
 }
 
 /// Input mode state
@@ -34,6 +74,8 @@ pub enum InputMode {
     Normal,
     /// Search/filter mode
     Search,
+    /// Search inside the focused preview
+    PreviewSearch,
     /// Text input for creating/renaming
     Input {
         prompt: String,
@@ -46,6 +88,15 @@ pub enum InputMode {
     },
 }
 
+/// A row in the session list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionRow {
+    /// Group header (index into `session_groups`)
+    Header(usize),
+    /// Session (index into `sessions`)
+    Session(usize),
+}
+
 /// Purpose of text input
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum InputPurpose {
@@ -53,7 +104,15 @@ pub enum InputPurpose {
     RenameSession,
     NewWindow,
     RenameWindow,
+    MoveWindow,
     TemplateVariable(String),
+    BulkRename,
+    SendCommand,
+    SendText,
+    Broadcast,
+    ScreenCommand,
+    SaveTemplate,
+    Grep,
     AddHostName,
     AddHostHostname,
     AddHostUser,
@@ -65,7 +124,8 @@ pub enum InputPurpose {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ConfirmAction {
     KillSession(String),
-    KillWindow(String, usize),
+    KillWindow(Target, usize),
+    Bulk(BulkOp, Vec<Target>),
 }
 
 /// Application state
@@ -91,13 +151,76 @@ pub struct App {
     /// All sessions (grouped by host)
     pub sessions: Vec<Session>,
 
-    /// Filtered sessions (after search)
+    /// Filtered sessions (after search), in display order
     pub filtered_sessions: Vec<usize>,
 
+    /// Filtered sessions split into groups
+    pub session_groups: Vec<SessionGroup>,
+
+    /// Rows shown in the session list (group headers and sessions)
+    pub session_rows: Vec<SessionRow>,
+
+    /// Labels of collapsed groups
+    pub collapsed_groups: HashSet<String>,
+
+    /// Matched name characters per session index (for search highlighting)
+    pub search_highlights: HashMap<usize, Vec<usize>>,
+
+    /// Sessions marked for bulk operations
+    pub marked: HashSet<Target>,
+
+    /// Row where the last mark was toggled (start of a `V` range)
+    mark_anchor: Option<usize>,
+
+    /// Windows marked in the Windows view (by number)
+    pub marked_windows: BTreeSet<usize>,
+
+    /// Per-session results of the last bulk operation
+    pub bulk_report: Option<Vec<BulkResult>>,
+
+    /// Mouse regions recorded while drawing the last frame
+    hit_map: HitMap,
+
+    /// Time and target of the last left click (for double-click detection)
+    last_click: Option<(Instant, HitTarget)>,
+
+    /// Session and window the current preview was captured from
+    preview_source: Option<(String, Option<usize>)>,
+
+    /// Window shown in the Sessions view preview (session id, window number)
+    pub preview_window: Option<(String, usize)>,
+
+    /// Pane receiving navigation keys
+    pub focus: Focus,
+
+    /// Sessions with output the user hasn't seen
+    pub unread: HashSet<Target>,
+
+    /// Output fingerprint of each session when the user last saw it
+    seen_output: HashMap<Target, u64>,
+
+    /// Position of the round-robin scan for unread output
+    unread_cursor: usize,
+
+    /// Remote capture for the unread scan, running off the UI task
+    remote_scan: Option<tokio::task::JoinHandle<(Target, Option<u64>)>>,
+
+    /// Search over the preview scrollback
+    pub preview_search: Option<ScrollbackSearch>,
+
+    /// Cursor and selection while copying from the preview
+    pub copy_mode: Option<CopyMode>,
+
+    /// Keep the preview scrolled to the bottom as it refreshes
+    pub preview_follow: bool,
+
+    /// Show the preview full-screen
+    pub preview_zoomed: bool,
+
     /// Currently selected host (None = local)
     pub selected_host: Option<String>,
 
-    /// Current session index
+    /// Current row index in the session list
     pub session_index: usize,
 
     /// Currently selected session (for windows view)
@@ -109,9 +232,30 @@ pub struct App {
     /// Current window index
     pub window_index: usize,
 
+    /// Tiles of the dashboard grid
+    pub dashboard: Vec<Tile>,
+
+    /// Selected dashboard tile
+    pub dashboard_index: usize,
+
+    /// View the dashboard returns to
+    dashboard_return: View,
+
+    /// Pattern of the last scrollback grep
+    pub grep_query: String,
+
+    /// Matches of the last scrollback grep
+    pub grep_hits: Vec<GrepHit>,
+
+    /// Selected grep match
+    pub grep_index: usize,
+
     /// Templates
     pub templates: Vec<Template>,
 
+    /// Projects discovered under the configured roots
+    pub projects: Vec<Project>,
+
     /// Current template index
     pub template_index: usize,
 
@@ -154,6 +298,21 @@ pub struct App {
     /// Fuzzy matcher
     matcher: SkimMatcherV2,
 
+    /// Attach history for frecency ranking
+    pub history: History,
+
+    /// Text previously sent to windows
+    send_history: InputHistory,
+
+    /// Screen commands entered at the `:` console
+    console_history: InputHistory,
+
+    /// When local sessions were last auto-snapshotted
+    last_snapshot: Instant,
+
+    /// Position in the prompt history while recalling (0 = newest)
+    history_cursor: Option<usize>,
+
     /// New host being added (name, hostname, user, port, identity_file)
     pub new_host: Option<(String, String, String, String, String)>,
 }
@@ -169,7 +328,13 @@ impl App {
 
         let show_preview = config.ui.show_preview;
 
-        let theme = crate::ui::theme::Theme::dark();
+        let (theme, theme_error) = match crate::ui::theme::Theme::from_settings(&config) {
+            Ok(theme) => (theme, None),
+            Err(e) => (
+                crate::ui::theme::Theme::dark(),
+                Some(format!("Failed to load theme: {:#}", e)),
+            ),
+        };
 
         let app = Self {
             config,
@@ -180,18 +345,46 @@ impl App {
             search_query: String::new(),
             sessions: Vec::new(),
             filtered_sessions: Vec::new(),
+            session_groups: Vec::new(),
+            session_rows: Vec::new(),
+            collapsed_groups: HashSet::new(),
+            search_highlights: HashMap::new(),
+            marked: HashSet::new(),
+            mark_anchor: None,
+            marked_windows: BTreeSet::new(),
+            bulk_report: None,
+            hit_map: HitMap::new(),
+            last_click: None,
+            preview_source: None,
+            preview_window: None,
+            focus: Focus::List,
+            preview_follow: false,
+            preview_search: None,
+            copy_mode: None,
+            unread: HashSet::new(),
+            seen_output: HashMap::new(),
+            unread_cursor: 0,
+            remote_scan: None,
+            preview_zoomed: false,
             selected_host: initial_host,
             session_index: 0,
             selected_session: None,
             windows: Vec::new(),
             window_index: 0,
+            dashboard: Vec::new(),
+            dashboard_index: 0,
+            dashboard_return: View::Sessions,
+            grep_query: String::new(),
+            grep_hits: Vec::new(),
+            grep_index: 0,
             templates: Vec::new(),
+            projects: Vec::new(),
             template_index: 0,
             preview: Preview::new(),
             show_preview,
             show_help: false,
             status_message: None,
-            error_message: None,
+            error_message: theme_error,
             should_quit: false,
             pending_attach: None,
             hosts,
@@ -200,6 +393,11 @@ impl App {
             settings_item_index: 0,
             theme,
             matcher: SkimMatcherV2::default(),
+            history: History::load_default(),
+            send_history: InputHistory::load_default("send"),
+            console_history: InputHistory::load_default("console"),
+            last_snapshot: Instant::now(),
+            history_cursor: None,
             new_host: None,
         };
 
@@ -208,14 +406,16 @@ impl App {
 
     /// Run the application main loop
     pub async fn run(&mut self) -> Result<()> {
-        let mut terminal = Terminal::new()?;
+        let mut terminal = Terminal::new(self.config.navigation.mouse_enabled)?;
         let mut events = EventHandler::new(EventConfig {
             tick_rate_ms: self.config.ui.refresh_interval_ms as u64,
             mouse_enabled: self.config.navigation.mouse_enabled,
         });
 
         // Initial data load
+        self.load_projects();
         self.refresh_sessions().await;
+        self.select_first_session();
         self.load_templates();
 
         // Initial preview update
@@ -225,31 +425,38 @@ impl App {
 
         loop {
             // Draw UI
+            let mut hits = HitMap::new();
             terminal.backend.draw(|frame| {
-                ui::draw(frame, self);
+                ui::draw(frame, self, &mut hits);
             })?;
+            self.hit_map = hits;
 
             // Handle events
             if let Some(event) = events.next().await {
                 match event {
                     AppEvent::Key(key) => {
-                        let in_input = matches!(self.input_mode, InputMode::Input { .. });
-                        let in_search = matches!(self.input_mode, InputMode::Search);
-                        let action = key_to_action(key, in_input, in_search);
+                        let action = self.key_action(key);
                         self.handle_action(action).await;
                     }
-                    AppEvent::Mouse(mouse) => {
-                        // Handle mouse events
-                        // TODO: Implement mouse handling with bounds from UI
-                    }
+                    AppEvent::Mouse(mouse) => self.handle_mouse(mouse).await,
                     AppEvent::Resize(_, _) => {
                         // Terminal will redraw automatically
                     }
                     AppEvent::Tick => {
-                        // Update preview if needed
-                        if self.show_preview && !self.sessions.is_empty() {
+                        // Update the dashboard or preview if needed
+                        if self.view == View::Dashboard {
+                            screen::dashboard::refresh(&self.config, &mut self.dashboard).await;
+                        } else if self.show_preview
+                            && !self.sessions.is_empty()
+                            && self.copy_mode.is_none()
+                        {
+                            // Copy mode works on a frozen capture
                             self.update_preview().await;
                         }
+                        if self.view == View::Sessions && self.config.ui.unread_markers {
+                            self.scan_unread().await;
+                        }
+                        self.auto_snapshot().await;
                     }
                     AppEvent::Error(e) => {
                         self.error_message = Some(e);
@@ -276,11 +483,42 @@ impl App {
         Ok(())
     }
 
+    /// Map a key to an action for the current mode
+    fn key_action(&self, key: KeyEvent) -> Action {
+        if self.copy_mode.is_some() && self.preview_focused() {
+            return copy_mode_action(key);
+        }
+
+        let in_input = matches!(self.input_mode, InputMode::Input { .. });
+        let in_search = matches!(
+            self.input_mode,
+            InputMode::Search | InputMode::PreviewSearch
+        );
+        match key_to_action(key, in_input, in_search) {
+            // `n` steps through preview matches instead of creating a session
+            Action::NewSession if self.preview_focused() && self.preview_search.is_some() => {
+                Action::SearchNext
+            }
+            action => action,
+        }
+    }
+
     /// Handle an action
     async fn handle_action(&mut self, action: Action) {
         // Clear messages
         self.status_message = None;
 
+        // The bulk result report is modal until dismissed
+        if self.bulk_report.is_some() {
+            if matches!(
+                action,
+                Action::Back | Action::Select | Action::Quit | Action::ForceQuit
+            ) {
+                self.bulk_report = None;
+            }
+            return;
+        }
+
         match action {
             Action::Quit => {
                 if matches!(self.input_mode, InputMode::Normal) && self.view == View::Sessions {
@@ -293,6 +531,15 @@ impl App {
                 self.should_quit = true;
             }
             Action::Back => self.go_back(),
+            Action::Up | Action::Down if matches!(self.input_mode, InputMode::Input { .. }) => {
+                self.recall_history(action == Action::Up)
+            }
+            Action::Up | Action::Down | Action::Top | Action::Bottom if self.preview_focused() => {
+                self.scroll_preview(action)
+            }
+            Action::PageUp | Action::PageDown if self.preview_focused() => {
+                self.scroll_preview(action)
+            }
             Action::Up => self.move_up(),
             Action::Down => self.move_down(),
             Action::Top => self.move_to_top(),
@@ -300,17 +547,35 @@ impl App {
             Action::PageUp => self.page_up(),
             Action::PageDown => self.page_down(),
             Action::Select => self.select().await,
+            Action::SearchNext => self.step_preview_search(true),
+            Action::SearchPrev => self.step_preview_search(false),
+            Action::NewSession if self.view == View::Windows => self.start_new_window(),
+            Action::RenameSession if self.view == View::Windows => self.start_rename_window(),
+            Action::KillSession if self.view == View::Windows => self.confirm_kill_window(),
             Action::NewSession => self.start_new_session(),
             Action::RenameSession => self.start_rename_session(),
-            Action::KillSession => self.confirm_kill_session(),
+            Action::KillSession => {
+                if self.has_marks() {
+                    self.confirm_bulk(BulkOp::Kill);
+                } else {
+                    self.confirm_kill_session();
+                }
+            }
             Action::DetachSession => {
                 // In Settings view with Hosts, 'd' deletes a host instead of detaching
                 if self.view == View::Settings && self.is_hosts_category() {
                     self.delete_host();
+                } else if self.has_marks() {
+                    self.confirm_bulk(BulkOp::Detach);
                 } else {
                     self.detach_session().await;
                 }
             }
+            Action::ToggleMark => self.toggle_mark(),
+            Action::MarkRange => self.mark_range(),
+            Action::MarkAll => self.mark_all(),
+            Action::SendCommand => self.start_send_command(),
+            Action::AttachSession if self.view == View::Dashboard => self.attach_tile().await,
             Action::AttachSession => {
                 // In Settings view with Hosts, 'a' adds a host instead of attaching
                 if self.view == View::Settings && self.is_hosts_category() {
@@ -324,22 +589,51 @@ impl App {
             Action::EditHost => {} // TODO
             Action::DeleteHost => self.delete_host(),
             Action::ViewWindows => self.view_windows().await,
+            Action::ViewDashboard => self.view_dashboard().await,
+            Action::SendText => self.start_send_text(),
+            Action::Console => self.start_console(),
+            Action::SaveTemplate => self.start_save_template(),
+            Action::Grep => self.start_grep(),
+            Action::EnterCopyMode => self.enter_copy_mode(),
+            Action::CopyMotion(motion) => self.copy_motion(motion),
+            Action::CopyVisual(kind) => {
+                if let Some(ref mut copy) = self.copy_mode {
+                    copy.toggle_visual(kind, &self.preview.lines);
+                }
+            }
+            Action::Yank => self.yank(),
+            Action::YankToBuffer => self.yank_to_buffer().await,
             Action::ViewTemplates => self.view = View::Templates,
             Action::ViewSettings => {
                 self.view = View::Settings;
                 self.settings_category_index = 0;
                 self.settings_item_index = 0;
             }
-            Action::Refresh => self.refresh_sessions().await,
+            Action::Refresh if self.view == View::Grep => {
+                let query = self.grep_query.clone();
+                self.run_grep(query).await;
+            }
+            Action::Refresh => {
+                self.load_projects();
+                self.refresh_sessions().await;
+            }
             Action::RefreshAll => {
                 self.refresh_sessions().await;
                 self.refresh_remote_sessions().await;
                 self.status_message = Some("Refreshed all (including remote)".to_string());
             }
+            Action::StartSearch if self.preview_focused() => {
+                self.input_mode = InputMode::PreviewSearch;
+                self.preview_search = Some(ScrollbackSearch::default());
+            }
             Action::StartSearch => {
                 self.input_mode = InputMode::Search;
                 self.search_query.clear();
             }
+            Action::ClearSearch if self.input_mode == InputMode::PreviewSearch => {
+                self.input_mode = InputMode::Normal;
+                self.preview_search = None;
+            }
             Action::ClearSearch => {
                 self.input_mode = InputMode::Normal;
                 self.search_query.clear();
@@ -350,21 +644,51 @@ impl App {
             }
             Action::TogglePreview => {
                 self.show_preview = !self.show_preview;
+                if !self.show_preview {
+                    self.focus = Focus::List;
+                    self.preview_zoomed = false;
+                }
             }
             Action::SwitchHost => self.switch_host(),
+            Action::FocusNext => self.focus_next(),
+            Action::ToggleFollow => self.toggle_follow(),
+            Action::ToggleZoom => self.toggle_zoom(),
+            Action::CyclePreviewWindow => self.cycle_preview_window().await,
+            Action::MoveWindowUp => self.shift_window(false).await,
+            Action::MoveWindowDown => self.shift_window(true).await,
+            Action::MoveWindow => self.start_move_window(),
+            Action::ToggleLineNumbers => {
+                self.config.ui.preview_line_numbers = !self.config.ui.preview_line_numbers;
+                let _ = self.config.save(None);
+            }
             Action::InputChar(c) => self.input_char(c),
             Action::InputBackspace => self.input_backspace(),
             Action::InputDelete => self.input_delete(),
             Action::InputConfirm => self.input_confirm().await,
             Action::InputCancel => self.input_cancel(),
+            Action::InputComplete => self.complete_input(),
+            Action::CycleSort => self.cycle_sort(),
+            Action::CycleGroup => self.cycle_group(),
+            Action::ToggleGroup => self.toggle_group(),
+            Action::Left if self.view == View::Dashboard => {
+                self.dashboard_index = self.dashboard_index.saturating_sub(1);
+            }
+            Action::Right if self.view == View::Dashboard => {
+                let max = self.dashboard.len().saturating_sub(1);
+                self.dashboard_index = (self.dashboard_index + 1).min(max);
+            }
             Action::Left => {
                 if self.view == View::Settings && self.settings_category_index > 0 {
                     self.settings_category_index -= 1;
                     self.settings_item_index = 0;
+                } else if self.view == View::Sessions {
+                    self.set_group_collapsed(true);
                 }
             }
             Action::Right => {
-                if self.view == View::Settings {
+                if self.view == View::Sessions {
+                    self.set_group_collapsed(false);
+                } else if self.view == View::Settings {
                     use crate::ui::settings::SettingsCategory;
                     let max = SettingsCategory::all().len().saturating_sub(1);
                     if self.settings_category_index < max {
@@ -377,6 +701,369 @@ impl App {
         }
     }
 
+    /// Handle a mouse event using the regions recorded in the last frame
+    async fn handle_mouse(&mut self, mouse: MouseEvent) {
+        let target = match self.hit_map.hit(mouse.column, mouse.row) {
+            Some(target) => target,
+            None => return,
+        };
+
+        // While a dialog or overlay is open only its buttons react
+        let modal = self.show_help
+            || self.bulk_report.is_some()
+            || matches!(
+                self.input_mode,
+                InputMode::Input { .. } | InputMode::Confirm { .. }
+            );
+
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                // Clicking elsewhere leaves copy mode
+                if !matches!(target, HitTarget::Preview | HitTarget::Hint(_)) {
+                    self.copy_mode = None;
+                }
+
+                let now = Instant::now();
+                let double = matches!(
+                    self.last_click,
+                    Some((at, last)) if last == target && now.duration_since(at) < DOUBLE_CLICK
+                );
+                self.last_click = if double { None } else { Some((now, target)) };
+
+                match target {
+                    HitTarget::Button(code) => self.press_key(code).await,
+                    _ if self.show_help => self.show_help = false,
+                    _ if modal => {}
+                    HitTarget::Hint(code) => self.press_key(code).await,
+                    HitTarget::ListRow(row) => {
+                        self.select_row(row);
+                        if double {
+                            self.handle_action(Action::Select).await;
+                        }
+                    }
+                    HitTarget::HostTab(index) => self.select_host(index),
+                    HitTarget::Preview => {
+                        if self.has_preview_pane() {
+                            self.focus = Focus::Preview;
+                        }
+                    }
+                }
+            }
+            MouseEventKind::ScrollUp if !modal => match target {
+                HitTarget::Preview => {
+                    self.preview_follow = false;
+                    self.preview.scroll_up(WHEEL_LINES);
+                }
+                HitTarget::ListRow(_) => self.move_up(),
+                _ => {}
+            },
+            MouseEventKind::ScrollDown if !modal => match target {
+                HitTarget::Preview => {
+                    let height = self.preview_height();
+                    self.preview.scroll_down(WHEEL_LINES, height);
+                }
+                HitTarget::ListRow(_) => self.move_down(),
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    /// Act as if a key was pressed in the current mode
+    async fn press_key(&mut self, code: crossterm::event::KeyCode) {
+        let action = self.key_action(KeyEvent::new(code, KeyModifiers::NONE));
+        self.handle_action(action).await;
+    }
+
+    /// Select a row in the current view's list
+    fn select_row(&mut self, row: usize) {
+        match self.view {
+            View::Sessions if row < self.session_rows.len() => self.session_index = row,
+            View::Windows if row < self.windows.len() => self.window_index = row,
+            View::Templates if row < self.templates.len() => self.template_index = row,
+            View::Dashboard if row < self.dashboard.len() => self.dashboard_index = row,
+            View::Grep if row < self.grep_hits.len() => self.grep_index = row,
+            _ => {}
+        }
+    }
+
+    /// Check whether the current view shows a preview pane
+    fn has_preview_pane(&self) -> bool {
+        self.show_preview && matches!(self.view, View::Sessions | View::Windows)
+    }
+
+    /// Check whether navigation keys go to the preview
+    fn preview_focused(&self) -> bool {
+        self.focus == Focus::Preview
+            && self.has_preview_pane()
+            && matches!(
+                self.input_mode,
+                InputMode::Normal | InputMode::PreviewSearch
+            )
+    }
+
+    /// Move focus between the list and the preview
+    fn focus_next(&mut self) {
+        self.focus = match self.focus {
+            Focus::List if self.has_preview_pane() => Focus::Preview,
+            _ => Focus::List,
+        };
+        if self.focus == Focus::List {
+            self.preview_zoomed = false;
+        }
+    }
+
+    /// Toggle pinning the preview to its last line
+    fn toggle_follow(&mut self) {
+        if !self.has_preview_pane() {
+            return;
+        }
+
+        self.preview_follow = !self.preview_follow;
+        if self.preview_follow {
+            self.scroll_preview_to_end();
+        }
+        self.status_message = Some(format!(
+            "Follow {}",
+            if self.preview_follow { "on" } else { "off" }
+        ));
+    }
+
+    /// Show the next window of the selected session in the preview
+    async fn cycle_preview_window(&mut self) {
+        if self.view != View::Sessions || !self.has_preview_pane() {
+            return;
+        }
+
+        let Some(session) = self
+            .get_selected_session()
+            .filter(|s| !s.is_virtual())
+            .cloned()
+        else {
+            return;
+        };
+
+        match screen::window::list(&self.config, session.host.as_deref(), &session.id).await {
+            Ok(windows) => {
+                let current = self.preview_window_number().unwrap_or(0);
+                let next = windows
+                    .iter()
+                    .find(|w| w.number > current)
+                    .or_else(|| windows.first());
+
+                if let Some(window) = next {
+                    self.preview_window = Some((session.id.clone(), window.number));
+                    self.status_message = Some(format!(
+                        "Previewing window {} ({}) of {}",
+                        window.number, window.name, session.name
+                    ));
+                    self.update_preview().await;
+                }
+            }
+            Err(e) => {
+                self.error_message = Some(format!("Failed to list windows: {}", e));
+            }
+        }
+    }
+
+    /// Window the preview captures
+    ///
+    /// In the Windows view this is the highlighted window; in the Sessions
+    /// view it is the window picked with `W`, if any, for the selected session.
+    pub fn preview_window_number(&self) -> Option<usize> {
+        match self.view {
+            View::Windows => self.windows.get(self.window_index).map(|w| w.number),
+            _ => {
+                let session = self.get_selected_session()?;
+                self.preview_window
+                    .as_ref()
+                    .filter(|(id, _)| *id == session.id)
+                    .map(|(_, number)| *number)
+            }
+        }
+    }
+
+    /// Toggle the full-screen preview
+    fn toggle_zoom(&mut self) {
+        if self.view == View::Dashboard {
+            self.preview_zoomed = !self.preview_zoomed;
+            return;
+        }
+
+        if !self.has_preview_pane() {
+            return;
+        }
+
+        self.preview_zoomed = !self.preview_zoomed;
+        self.focus = if self.preview_zoomed {
+            Focus::Preview
+        } else {
+            Focus::List
+        };
+    }
+
+    /// Scroll the preview with a navigation action
+    fn scroll_preview(&mut self, action: Action) {
+        let height = self.preview_height();
+
+        match action {
+            Action::Up => self.preview.scroll_up(1),
+            Action::Down => self.preview.scroll_down(1, height),
+            Action::PageUp => self.preview.scroll_up(height.max(1)),
+            Action::PageDown => self.preview.scroll_down(height.max(1), height),
+            Action::Top => self.preview.scroll_offset = 0,
+            Action::Bottom => self.scroll_preview_to_end(),
+            _ => {}
+        }
+
+        // Scrolling away from the bottom stops following
+        let max_offset = self.preview.lines.len().saturating_sub(height);
+        if self.preview.scroll_offset < max_offset {
+            self.preview_follow = false;
+        }
+    }
+
+    /// Re-run the preview search as the query is typed
+    fn update_preview_search(&mut self) {
+        let line = self.preview.scroll_offset;
+        let found = self.preview_search.as_mut().and_then(|search| {
+            search.update(&self.preview.lines);
+            search.jump_from(line)
+        });
+        if let Some(found) = found {
+            self.scroll_preview_to_line(found.line);
+        }
+    }
+
+    /// Jump to the next or previous preview search match
+    fn step_preview_search(&mut self, forward: bool) {
+        if !self.preview_focused() {
+            return;
+        }
+
+        if let Some(found) = self
+            .preview_search
+            .as_mut()
+            .and_then(|search| search.step(forward))
+        {
+            self.scroll_preview_to_line(found.line);
+        }
+    }
+
+    /// Scroll the preview so a line is visible, stopping follow mode
+    fn scroll_preview_to_line(&mut self, line: usize) {
+        let height = self.preview_height().max(1);
+        let offset = self.preview.scroll_offset;
+
+        if line < offset || line >= offset + height {
+            let max_offset = self.preview.lines.len().saturating_sub(height);
+            self.preview.scroll_offset = line.saturating_sub(height / 3).min(max_offset);
+        }
+        self.preview_follow = false;
+    }
+
+    /// Start copy mode in the preview
+    ///
+    /// The cursor starts on the current search match, or on the last
+    /// non-blank line on screen.
+    fn enter_copy_mode(&mut self) {
+        if !self.has_preview_pane() || self.preview.lines.is_empty() {
+            return;
+        }
+        self.focus = Focus::Preview;
+
+        let line = match self.preview_search.as_ref().and_then(|s| s.current_match()) {
+            Some(found) => found.line,
+            None => {
+                let offset = self.preview.scroll_offset;
+                let end = (offset + self.preview_height().max(1)).min(self.preview.lines.len());
+                self.preview.lines[offset..end]
+                    .iter()
+                    .rposition(|line| !line.trim().is_empty())
+                    .map_or(offset, |i| offset + i)
+            }
+        };
+
+        self.copy_mode = Some(CopyMode::new(line));
+        self.preview_follow = false;
+    }
+
+    /// Move the copy-mode cursor, keeping it on screen
+    fn copy_motion(&mut self, motion: Motion) {
+        let height = self.preview_height();
+        let Some(ref mut copy) = self.copy_mode else {
+            return;
+        };
+
+        copy.motion(motion, &self.preview.lines, height);
+        let line = copy.line;
+        self.scroll_preview_to_line(line);
+    }
+
+    /// Copy the selection to the clipboard and leave copy mode
+    fn yank(&mut self) {
+        let Some(copy) = self.copy_mode.take() else {
+            return;
+        };
+
+        let text = copy.selected_text(&self.preview.lines);
+        let command = self.config.integrations.clipboard_command.as_deref();
+        match crate::integrations::clipboard::copy(command, &text) {
+            Ok(destination) => {
+                self.status_message = Some(format!(
+                    "Copied {} line(s) to the {}",
+                    text.lines().count().max(1),
+                    destination
+                ));
+            }
+            Err(e) => {
+                self.error_message = Some(format!("Failed to copy: {}", e));
+            }
+        }
+    }
+
+    /// Put the selection in the previewed session's paste buffer and leave copy mode
+    async fn yank_to_buffer(&mut self) {
+        let Some(copy) = self.copy_mode.take() else {
+            return;
+        };
+        let Some(session) = self.get_selected_session().cloned() else {
+            return;
+        };
+
+        let text = copy.selected_text(&self.preview.lines);
+        let result = match session.host {
+            Some(ref host) => {
+                screen::remote::set_paste_buffer(&self.config, host, &session.id, &text).await
+            }
+            None => screen::local::set_paste_buffer(&session.id, &text).await,
+        };
+
+        match result {
+            Ok(()) => {
+                self.status_message =
+                    Some(format!("Copied to the paste buffer of '{}'", session.name));
+            }
+            Err(e) => {
+                self.error_message = Some(format!("{}", e));
+            }
+        }
+    }
+
+    /// Scroll the preview so its last line is visible
+    fn scroll_preview_to_end(&mut self) {
+        let height = self.preview_height();
+        self.preview.scroll_offset = self.preview.lines.len().saturating_sub(height);
+    }
+
+    /// Visible height of the preview pane in the last frame
+    fn preview_height(&self) -> usize {
+        self.hit_map
+            .area_of(HitTarget::Preview)
+            .map(|area| area.height.saturating_sub(2) as usize)
+            .unwrap_or(0)
+    }
+
     /// Go back to previous view/mode
     fn go_back(&mut self) {
         // First check if help overlay is shown
@@ -385,6 +1072,30 @@ impl App {
             return;
         }
 
+        // End a copy-mode selection, then copy mode itself
+        if let Some(ref mut copy) = self.copy_mode {
+            if copy.visual.take().is_none() {
+                self.copy_mode = None;
+            }
+            return;
+        }
+
+        // Drop a preview search, leave zoom, then give focus back to the list
+        if matches!(self.input_mode, InputMode::Normal) {
+            if self.preview_search.is_some() && self.preview_focused() {
+                self.preview_search = None;
+                return;
+            }
+            if self.preview_zoomed {
+                self.preview_zoomed = false;
+                return;
+            }
+            if self.focus == Focus::Preview {
+                self.focus = Focus::List;
+                return;
+            }
+        }
+
         match &self.input_mode {
             InputMode::Normal => match self.view {
                 View::Windows => {
@@ -403,13 +1114,26 @@ impl App {
                     let _ = self.config.save(None);
                     self.view = View::Sessions;
                 }
-                View::Sessions => {}
+                View::Sessions => {
+                    self.clear_marks();
+                }
+                View::Dashboard => {
+                    self.view = self.dashboard_return;
+                    self.dashboard.clear();
+                }
+                View::Grep => {
+                    self.view = View::Sessions;
+                }
             },
             InputMode::Search => {
                 self.input_mode = InputMode::Normal;
                 self.search_query.clear();
                 self.apply_filter();
             }
+            InputMode::PreviewSearch => {
+                self.input_mode = InputMode::Normal;
+                self.preview_search = None;
+            }
             InputMode::Input { .. } | InputMode::Confirm { .. } => {
                 self.input_mode = InputMode::Normal;
                 self.input_buffer.clear();
@@ -440,6 +1164,15 @@ impl App {
                     self.settings_item_index -= 1;
                 }
             }
+            View::Dashboard => {
+                let columns = screen::dashboard::grid_columns(self.dashboard.len());
+                if self.dashboard_index >= columns {
+                    self.dashboard_index -= columns;
+                }
+            }
+            View::Grep => {
+                self.grep_index = self.grep_index.saturating_sub(1);
+            }
             View::Help => {}
         }
     }
@@ -448,7 +1181,7 @@ impl App {
     fn move_down(&mut self) {
         match self.view {
             View::Sessions => {
-                let max = self.filtered_sessions.len().saturating_sub(1);
+                let max = self.session_rows.len().saturating_sub(1);
                 if self.session_index < max {
                     self.session_index += 1;
                 }
@@ -471,6 +1204,18 @@ impl App {
                     self.settings_item_index += 1;
                 }
             }
+            View::Dashboard => {
+                let columns = screen::dashboard::grid_columns(self.dashboard.len());
+                if self.dashboard_index + columns < self.dashboard.len() {
+                    self.dashboard_index += columns;
+                }
+            }
+            View::Grep => {
+                let max = self.grep_hits.len().saturating_sub(1);
+                if self.grep_index < max {
+                    self.grep_index += 1;
+                }
+            }
             View::Help => {}
         }
     }
@@ -482,6 +1227,8 @@ impl App {
             View::Windows => self.window_index = 0,
             View::Templates => self.template_index = 0,
             View::Settings => self.settings_item_index = 0,
+            View::Dashboard => self.dashboard_index = 0,
+            View::Grep => self.grep_index = 0,
             View::Help => {}
         }
     }
@@ -490,7 +1237,7 @@ impl App {
     fn move_to_bottom(&mut self) {
         match self.view {
             View::Sessions => {
-                self.session_index = self.filtered_sessions.len().saturating_sub(1);
+                self.session_index = self.session_rows.len().saturating_sub(1);
             }
             View::Windows => {
                 self.window_index = self.windows.len().saturating_sub(1);
@@ -501,6 +1248,12 @@ impl App {
             View::Settings => {
                 self.settings_item_index = self.get_settings_item_count().saturating_sub(1);
             }
+            View::Dashboard => {
+                self.dashboard_index = self.dashboard.len().saturating_sub(1);
+            }
+            View::Grep => {
+                self.grep_index = self.grep_hits.len().saturating_sub(1);
+            }
             View::Help => {}
         }
     }
@@ -520,6 +1273,12 @@ impl App {
             View::Settings => {
                 self.settings_item_index = self.settings_item_index.saturating_sub(10);
             }
+            View::Dashboard => {
+                self.dashboard_index = self.dashboard_index.saturating_sub(10);
+            }
+            View::Grep => {
+                self.grep_index = self.grep_index.saturating_sub(10);
+            }
             View::Help => {}
         }
     }
@@ -528,7 +1287,7 @@ impl App {
     fn page_down(&mut self) {
         match self.view {
             View::Sessions => {
-                let max = self.filtered_sessions.len().saturating_sub(1);
+                let max = self.session_rows.len().saturating_sub(1);
                 self.session_index = (self.session_index + 10).min(max);
             }
             View::Windows => {
@@ -543,6 +1302,14 @@ impl App {
                 let max = self.get_settings_item_count().saturating_sub(1);
                 self.settings_item_index = (self.settings_item_index + 10).min(max);
             }
+            View::Dashboard => {
+                let max = self.dashboard.len().saturating_sub(1);
+                self.dashboard_index = (self.dashboard_index + 10).min(max);
+            }
+            View::Grep => {
+                let max = self.grep_hits.len().saturating_sub(1);
+                self.grep_index = (self.grep_index + 10).min(max);
+            }
             View::Help => {}
         }
     }
@@ -571,15 +1338,27 @@ impl App {
             InputMode::Normal => {
                 match self.view {
                     View::Sessions => {
-                        // Attach to selected session
-                        self.attach_session(false).await;
+                        // Group headers toggle, sessions attach
+                        if let Some(SessionRow::Header(_)) =
+                            self.session_rows.get(self.session_index)
+                        {
+                            self.toggle_group();
+                        } else {
+                            self.attach_session(false).await;
+                        }
                     }
                     View::Windows => {
                         // Select window and attach
-                        if let Some(window) = self.windows.get(self.window_index) {
-                            if let Some(ref session) = self.selected_session {
-                                let _ = screen::local::select_window(session, window.number).await;
-                            }
+                        if let (Some(window), Some(session)) =
+                            (self.windows.get(self.window_index), self.windows_session())
+                        {
+                            let _ = screen::window::run(
+                                &self.config,
+                                session.host.as_deref(),
+                                &session.id,
+                                &WindowOp::Select(window.number),
+                            )
+                            .await;
                         }
                         self.attach_session(false).await;
                     }
@@ -592,6 +1371,17 @@ impl App {
                     View::Help => {
                         self.show_help = false;
                     }
+                    View::Dashboard => {
+                        // Zoom into the tile, attach once zoomed
+                        if self.preview_zoomed {
+                            self.attach_tile().await;
+                        } else if !self.dashboard.is_empty() {
+                            self.preview_zoomed = true;
+                        }
+                    }
+                    View::Grep => {
+                        self.open_grep_hit().await;
+                    }
                 }
             }
             _ => {}
@@ -599,27 +1389,111 @@ impl App {
     }
 
     /// Get currently selected session
-    fn get_selected_session(&self) -> Option<&Session> {
-        if self.filtered_sessions.is_empty() {
-            return None;
+    pub fn get_selected_session(&self) -> Option<&Session> {
+        match self.session_rows.get(self.session_index)? {
+            SessionRow::Session(idx) => self.sessions.get(*idx),
+            SessionRow::Header(_) => None,
         }
-        let idx = self.filtered_sessions.get(self.session_index)?;
-        self.sessions.get(*idx)
     }
 
-    /// Start creating a new session
-    fn start_new_session(&mut self) {
-        self.input_mode = InputMode::Input {
-            prompt: "Session name:".to_string(),
-            purpose: InputPurpose::NewSession,
-        };
-        self.input_buffer.clear();
-        self.input_cursor = 0;
+    /// Index into `session_groups` of the group containing the selected row
+    fn selected_group(&self) -> Option<usize> {
+        match self.session_rows.get(self.session_index)? {
+            SessionRow::Header(group) => Some(*group),
+            SessionRow::Session(idx) => self
+                .session_groups
+                .iter()
+                .position(|g| g.sessions.contains(idx)),
+        }
+    }
+
+    /// Cycle the session sort order
+    fn cycle_sort(&mut self) {
+        self.config.ui.sort_by = self.config.ui.sort_by.next();
+        let _ = self.config.save(None);
+        self.apply_filter();
+        self.status_message = Some(format!("Sort by {}", self.config.ui.sort_by));
+    }
+
+    /// Cycle the session grouping
+    fn cycle_group(&mut self) {
+        self.config.ui.group_by = self.config.ui.group_by.next();
+        let _ = self.config.save(None);
+        self.collapsed_groups.clear();
+        self.apply_filter();
+        self.select_first_session();
+        self.status_message = Some(format!("Group by {}", self.config.ui.group_by));
+    }
+
+    /// Toggle collapse of the selected group
+    fn toggle_group(&mut self) {
+        if let Some(group) = self.selected_group() {
+            let label = &self.session_groups[group].label;
+            let collapsed = self.collapsed_groups.contains(label);
+            self.set_group_collapsed(!collapsed);
+        }
+    }
+
+    /// Collapse or expand the selected group, keeping its header selected
+    fn set_group_collapsed(&mut self, collapsed: bool) {
+        if self.config.ui.group_by == GroupBy::None {
+            return;
+        }
+
+        if let Some(group) = self.selected_group() {
+            let label = self.session_groups[group].label.clone();
+            if collapsed {
+                self.collapsed_groups.insert(label);
+            } else {
+                self.collapsed_groups.remove(&label);
+            }
+            self.rebuild_rows();
+            if let Some(pos) = self
+                .session_rows
+                .iter()
+                .position(|r| *r == SessionRow::Header(group))
+            {
+                self.session_index = pos;
+            }
+        }
+    }
+
+    /// Select the first session row (skipping group headers)
+    fn select_first_session(&mut self) {
+        self.session_index = self
+            .session_rows
+            .iter()
+            .position(|r| matches!(r, SessionRow::Session(_)))
+            .unwrap_or(0);
+    }
+
+    /// Start creating a new session
+    fn start_new_session(&mut self) {
+        self.input_mode = InputMode::Input {
+            prompt: "Session name:".to_string(),
+            purpose: InputPurpose::NewSession,
+        };
+        self.input_buffer.clear();
+        self.input_cursor = 0;
     }
 
     /// Start renaming a session
     fn start_rename_session(&mut self) {
-        if let Some(session) = self.get_selected_session().cloned() {
+        if self.has_marks() {
+            self.input_mode = InputMode::Input {
+                prompt: "Rename pattern ({name}, {n} or s/re/rep/):".to_string(),
+                purpose: InputPurpose::BulkRename,
+            };
+            self.input_buffer = "{name}".to_string();
+            self.input_cursor = self.input_buffer.len();
+            return;
+        }
+
+        if let Some(session) = self
+            .get_selected_session()
+            .filter(|s| !s.is_virtual())
+            .cloned()
+        {
             self.input_mode = InputMode::Input {
                 prompt: format!("Rename '{}' to:", session.name),
                 purpose: InputPurpose::RenameSession,
@@ -629,6 +1503,442 @@ impl App {
         }
     }
 
+    /// Start prompting for a screen command to send
+    fn start_send_command(&mut self) {
+        if self.view != View::Sessions || self.bulk_targets().is_empty() {
+            return;
+        }
+
+        self.input_mode = InputMode::Input {
+            prompt: "Screen command:".to_string(),
+            purpose: InputPurpose::SendCommand,
+        };
+        self.input_buffer.clear();
+        self.input_cursor = 0;
+    }
+
+    /// Session and window that typed text goes to
+    ///
+    /// The highlighted window in the Windows view, otherwise the window the
+    /// preview shows (None for the session's current window).
+    fn send_target(&self) -> Option<(Session, Option<usize>)> {
+        let session = match self.view {
+            View::Windows => self.windows_session()?,
+            View::Sessions => self
+                .get_selected_session()
+                .filter(|s| !s.is_virtual())?
+                .clone(),
+            _ => return None,
+        };
+        Some((session, self.preview_window_number()))
+    }
+
+    /// Prompt for text to type into the selected session or window
+    ///
+    /// With marked sessions or windows the prompt broadcasts to all of them.
+    fn start_send_text(&mut self) {
+        let targets = self.broadcast_targets();
+        if !targets.is_empty() {
+            self.start_broadcast(targets.len());
+            return;
+        }
+
+        let Some((session, window)) = self.send_target() else {
+            return;
+        };
+
+        let label = match window {
+            Some(number) => format!("{}:{}", session.display_name(), number),
+            None => session.display_name(),
+        };
+        self.input_mode = InputMode::Input {
+            prompt: format!("Send to {} (Up/Down for history):", label),
+            purpose: InputPurpose::SendText,
+        };
+        self.input_buffer.clear();
+        self.input_cursor = 0;
+        self.history_cursor = None;
+    }
+
+    /// Type text followed by Enter into the send target
+    async fn send_text(&mut self, text: String) {
+        self.history_cursor = None;
+        let Some((session, window)) = self.send_target() else {
+            return;
+        };
+
+        let _ = self.send_history.push(&text);
+        let host = session.host.as_deref();
+        match screen::send::send(&self.config, host, &session.id, window, &text, true).await {
+            Ok(()) => {
+                self.status_message = Some(format!("Sent to {}", session.display_name()));
+            }
+            Err(e) => {
+                self.error_message = Some(format!("Failed to send text: {}", e));
+            }
+        }
+    }
+
+    /// Marked windows of the Windows view session, or marked sessions
+    ///
+    /// Empty when nothing is marked.
+    fn broadcast_targets(&self) -> Vec<BroadcastTarget> {
+        match self.view {
+            View::Windows => match self.windows_session() {
+                Some(session) => self
+                    .windows
+                    .iter()
+                    .filter(|w| self.marked_windows.contains(&w.number))
+                    .map(|w| BroadcastTarget {
+                        session: Target::from_session(&session),
+                        window: Some(w.number),
+                    })
+                    .collect(),
+                None => Vec::new(),
+            },
+            View::Sessions if self.has_marks() => self
+                .bulk_targets()
+                .into_iter()
+                .map(|session| BroadcastTarget {
+                    session,
+                    window: None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Open the broadcast prompt, which stays open after each line
+    fn start_broadcast(&mut self, count: usize) {
+        self.input_mode = InputMode::Input {
+            prompt: format!("Broadcast to {} targets (Esc to stop):", count),
+            purpose: InputPurpose::Broadcast,
+        };
+        self.input_buffer.clear();
+        self.input_cursor = 0;
+        self.history_cursor = None;
+    }
+
+    /// Type a line into every marked target and report the results
+    ///
+    /// The prompt reopens for the next line unless a target failed, in
+    /// which case the per-target report is shown.
+    async fn broadcast_text(&mut self, text: String) {
+        self.history_cursor = None;
+        let targets = self.broadcast_targets();
+        if targets.is_empty() {
+            return;
+        }
+
+        let _ = self.send_history.push(&text);
+        let results = broadcast::broadcast(&self.config, &targets, &text, true).await;
+        let succeeded = results.iter().filter(|r| r.is_ok()).count();
+
+        if succeeded == results.len() {
+            let labels: Vec<&str> = results.iter().map(|r| r.label.as_str()).collect();
+            self.status_message = Some(format!("Sent to {}", labels.join(", ")));
+            self.start_broadcast(targets.len());
+        } else {
+            self.error_message = Some(format!(
+                "Broadcast: {} of {} succeeded",
+                succeeded,
+                results.len()
+            ));
+            self.bulk_report = Some(results);
+        }
+    }
+
+    /// Open the `:` prompt for a screen command to the selected session or window
+    fn start_console(&mut self) {
+        let Some((session, window)) = self.send_target() else {
+            return;
+        };
+
+        let label = match window {
+            Some(number) => format!("{}:{}", session.display_name(), number),
+            None => session.display_name(),
+        };
+        self.input_mode = InputMode::Input {
+            prompt: format!("Screen command for {} (Tab completes):", label),
+            purpose: InputPurpose::ScreenCommand,
+        };
+        self.input_buffer.clear();
+        self.input_cursor = 0;
+        self.history_cursor = None;
+    }
+
+    /// Run a console command and show its result or query reply
+    async fn run_console_command(&mut self, command: String) {
+        self.history_cursor = None;
+        let Some((session, window)) = self.send_target() else {
+            return;
+        };
+
+        let _ = self.console_history.push(&command);
+        let host = session.host.as_deref();
+        match screen::console::run(&self.config, host, &session.id, window, &command).await {
+            Ok(Some(reply)) => {
+                self.status_message = Some(reply.lines().collect::<Vec<_>>().join(" | "));
+            }
+            Ok(None) => {
+                self.status_message =
+                    Some(format!("Sent '{}' to {}", command, session.display_name()));
+            }
+            Err(e) => {
+                self.error_message = Some(format!("Screen command failed: {}", e));
+            }
+        }
+    }
+
+    /// Complete the screen command name in the console prompt
+    ///
+    /// Completes as far as the matches agree; when that adds nothing, the
+    /// matches are listed in the message area.
+    fn complete_input(&mut self) {
+        if !matches!(
+            self.input_mode,
+            InputMode::Input {
+                purpose: InputPurpose::ScreenCommand,
+                ..
+            }
+        ) {
+            return;
+        }
+
+        let Some((completed, matches)) = screen::console::complete(&self.input_buffer) else {
+            return;
+        };
+        if completed.len() > self.input_buffer.trim_start().len() {
+            self.input_buffer = completed;
+            self.input_cursor = self.input_buffer.len();
+        } else if !matches.is_empty() {
+            self.status_message = Some(matches.join(" "));
+        }
+    }
+
+    /// History of the open prompt, if it keeps one
+    fn prompt_history(&self) -> Option<&InputHistory> {
+        match &self.input_mode {
+            InputMode::Input {
+                purpose: InputPurpose::SendText | InputPurpose::Broadcast,
+                ..
+            } => Some(&self.send_history),
+            InputMode::Input {
+                purpose: InputPurpose::ScreenCommand,
+                ..
+            } => Some(&self.console_history),
+            _ => None,
+        }
+    }
+
+    /// Replace the prompt input with an older or newer history entry
+    fn recall_history(&mut self, older: bool) {
+        let Some(history) = self.prompt_history() else {
+            return;
+        };
+
+        let next = match (self.history_cursor, older) {
+            (None, true) => Some(0),
+            (Some(i), true) => Some(i + 1),
+            (Some(0), false) | (None, false) => None,
+            (Some(i), false) => Some(i - 1),
+        };
+
+        match next.and_then(|i| history.recall(i).map(|entry| (i, entry.to_string()))) {
+            Some((i, entry)) => {
+                self.history_cursor = Some(i);
+                self.input_buffer = entry;
+            }
+            // Past the oldest entry keep it; past the newest clear the input
+            None if older => return,
+            None => {
+                self.history_cursor = None;
+                self.input_buffer.clear();
+            }
+        }
+        self.input_cursor = self.input_buffer.len();
+    }
+
+    /// Check whether any listed session is marked
+    fn has_marks(&self) -> bool {
+        self.view == View::Sessions && !self.marked.is_empty()
+    }
+
+    /// Sessions a bulk operation applies to, in display order
+    ///
+    /// Marked sessions if any, otherwise the selected session.
+    fn bulk_targets(&self) -> Vec<Target> {
+        if self.marked.is_empty() {
+            return self
+                .get_selected_session()
+                .filter(|s| !s.is_virtual())
+                .map(Target::from_session)
+                .into_iter()
+                .collect();
+        }
+
+        self.filtered_sessions
+            .iter()
+            .filter_map(|&idx| self.sessions.get(idx))
+            .map(Target::from_session)
+            .filter(|t| self.marked.contains(t))
+            .collect()
+    }
+
+    /// Toggle the mark on the selected session (or window in the Windows view)
+    fn toggle_mark(&mut self) {
+        if self.view == View::Windows {
+            if let Some(number) = self.windows.get(self.window_index).map(|w| w.number) {
+                if !self.marked_windows.remove(&number) {
+                    self.marked_windows.insert(number);
+                }
+                self.move_down();
+            }
+            return;
+        }
+        if self.view != View::Sessions {
+            return;
+        }
+
+        if let Some(session) = self.get_selected_session().filter(|s| !s.is_virtual()) {
+            let target = Target::from_session(session);
+            if !self.marked.remove(&target) {
+                self.marked.insert(target);
+            }
+            self.mark_anchor = Some(self.session_index);
+            self.move_down();
+        }
+    }
+
+    /// Mark every session between the last toggled row and the selection
+    fn mark_range(&mut self) {
+        if self.view != View::Sessions || self.session_rows.is_empty() {
+            return;
+        }
+
+        let anchor = self.mark_anchor.unwrap_or(self.session_index);
+        let (start, end) = if anchor <= self.session_index {
+            (anchor, self.session_index)
+        } else {
+            (self.session_index, anchor)
+        };
+
+        let end = end.min(self.session_rows.len() - 1);
+        let targets: Vec<Target> = self.session_rows[start..=end]
+            .iter()
+            .filter_map(|row| match row {
+                SessionRow::Session(idx) => self.sessions.get(*idx),
+                SessionRow::Header(_) => None,
+            })
+            .filter(|s| !s.is_virtual())
+            .map(Target::from_session)
+            .collect();
+
+        self.marked.extend(targets);
+        self.mark_anchor = Some(self.session_index);
+        self.status_message = Some(format!("{} marked", self.marked.len()));
+    }
+
+    /// Mark every session matching the filter, or clear marks if all are marked
+    ///
+    /// In the Windows view this applies to the session's windows.
+    fn mark_all(&mut self) {
+        if self.view == View::Windows {
+            if self.marked_windows.len() == self.windows.len() {
+                self.marked_windows.clear();
+            } else {
+                self.marked_windows = self.windows.iter().map(|w| w.number).collect();
+                self.status_message = Some(format!("{} marked", self.marked_windows.len()));
+            }
+            return;
+        }
+        if self.view != View::Sessions {
+            return;
+        }
+
+        let targets: Vec<Target> = self
+            .filtered_sessions
+            .iter()
+            .filter_map(|&idx| self.sessions.get(idx))
+            .filter(|s| !s.is_virtual())
+            .map(Target::from_session)
+            .collect();
+
+        if targets.iter().all(|t| self.marked.contains(t)) {
+            self.clear_marks();
+        } else {
+            self.marked.extend(targets);
+            self.status_message = Some(format!("{} marked", self.marked.len()));
+        }
+    }
+
+    /// Clear all marks
+    fn clear_marks(&mut self) {
+        self.marked.clear();
+        self.mark_anchor = None;
+        self.marked_windows.clear();
+    }
+
+    /// Ask for confirmation before applying an operation to the marked sessions
+    fn confirm_bulk(&mut self, op: BulkOp) {
+        let targets = self.bulk_targets();
+        if targets.is_empty() {
+            return;
+        }
+
+        self.input_mode = InputMode::Confirm {
+            message: bulk::confirm_message(&op, &targets),
+            action: ConfirmAction::Bulk(op, targets),
+        };
+    }
+
+    /// Apply an operation to several sessions and report the results
+    async fn run_bulk(&mut self, op: BulkOp, targets: Vec<Target>) {
+        let results = bulk::run(&self.config, &op, &targets).await;
+        let succeeded = results.iter().filter(|r| r.is_ok()).count();
+
+        let summary = format!(
+            "{}: {} of {} succeeded",
+            op.verb(),
+            succeeded,
+            results.len()
+        );
+        if succeeded == results.len() {
+            self.status_message = Some(summary);
+        } else {
+            self.error_message = Some(summary);
+        }
+
+        if results.len() > 1 || succeeded < results.len() {
+            self.bulk_report = Some(results);
+        }
+
+        if matches!(op, BulkOp::Kill | BulkOp::Rename(_)) {
+            self.clear_marks();
+        }
+        self.refresh_sessions().await;
+        if targets.iter().any(|t| t.host.is_some()) {
+            self.refresh_remote_sessions().await;
+        }
+    }
+
+    /// Snapshot local sessions when the configured interval has passed
+    async fn auto_snapshot(&mut self) {
+        let minutes = self.config.screen.snapshot_interval_minutes;
+        if minutes == 0 || self.last_snapshot.elapsed() < Duration::from_secs(minutes * 60) {
+            return;
+        }
+        self.last_snapshot = Instant::now();
+
+        let snapshot = Snapshot::capture(&self.sessions).await;
+        if snapshot.sessions.is_empty() {
+            return;
+        }
+        if let Err(e) = snapshot::save_auto(&snapshot, self.config.screen.snapshot_keep) {
+            self.error_message = Some(format!("Auto-snapshot failed: {}", e));
+        }
+    }
+
     /// Toggle a setting value
     fn toggle_setting(&mut self) {
         use crate::ui::settings::{apply_setting, get_settings_for_category, SettingsCategory};
@@ -639,15 +1949,27 @@ impl App {
             if let Some(item) = settings_items.get_mut(self.settings_item_index) {
                 item.value.toggle();
                 apply_setting(&mut self.config, &item.key, &item.value);
+                if item.key == "ui.theme" {
+                    self.reload_theme();
+                }
+                self.apply_filter();
                 // Save immediately
                 let _ = self.config.save(None);
             }
         }
     }
 
+    /// Reload the theme after the setting changed
+    fn reload_theme(&mut self) {
+        match crate::ui::theme::Theme::from_settings(&self.config) {
+            Ok(theme) => self.theme = theme,
+            Err(e) => self.error_message = Some(format!("Failed to load theme: {:#}", e)),
+        }
+    }
+
     /// Confirm killing a session
     fn confirm_kill_session(&mut self) {
-        if let Some(session) = self.get_selected_session() {
+        if let Some(session) = self.get_selected_session().filter(|s| !s.is_virtual()) {
             self.input_mode = InputMode::Confirm {
                 message: format!("Kill session '{}'?", session.name),
                 action: ConfirmAction::KillSession(session.id.clone()),
@@ -674,10 +1996,14 @@ impl App {
                     self.error_message = Some(format!("Failed to kill session: {}", e));
                 }
             },
-            ConfirmAction::KillWindow(session, number) => {
-                match screen::local::kill_window(&session, number).await {
+            ConfirmAction::Bulk(op, targets) => self.run_bulk(op, targets).await,
+            ConfirmAction::KillWindow(target, number) => {
+                let op = WindowOp::Kill(number);
+                match screen::window::run(&self.config, target.host.as_deref(), &target.id, &op)
+                    .await
+                {
                     Ok(_) => {
-                        self.status_message = Some(format!("Killed window {}", number));
+                        self.status_message = Some(op.done_message());
                         self.refresh_windows().await;
                     }
                     Err(e) => {
@@ -690,7 +2016,7 @@ impl App {
 
     /// Detach selected session
     async fn detach_session(&mut self) {
-        if let Some(session) = self.get_selected_session() {
+        if let Some(session) = self.get_selected_session().filter(|s| !s.is_virtual()) {
             let id = session.id.clone();
             let host = session.host.clone();
 
@@ -718,6 +2044,19 @@ impl App {
             let id = session.id.clone();
             let host = session.host.clone();
 
+            let name = session.name.clone();
+
+            // Discovered projects get their session created first
+            if session.is_virtual() {
+                let dir = session.working_dir.clone().unwrap_or_default();
+                if let Err(e) = self.start_project(&id, &dir).await {
+                    self.error_message = Some(format!("Failed to create session: {}", e));
+                    return;
+                }
+            }
+
+            self.record_history(&name, host.as_deref());
+
             if spawn {
                 match screen::local::attach_spawn(&self.config, &id, host.as_deref()).await {
                     Ok(_) => {
@@ -735,20 +2074,281 @@ impl App {
         }
     }
 
+    /// Record a session use in the attach history
+    fn record_history(&mut self, name: &str, host: Option<&str>) {
+        if let Err(e) = self.history.record(name, host) {
+            self.error_message = Some(format!("Failed to save history: {}", e));
+        }
+    }
+
+    /// Create the session for a discovered project
+    async fn start_project(&mut self, name: &str, dir: &str) -> Result<()> {
+        let project = self
+            .projects
+            .iter()
+            .find(|p| p.root.to_string_lossy() == dir)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Project '{}' not found", name))?;
+
+        project.create_session(&self.config, name).await?;
+        self.status_message = Some(format!("Created session '{}'", name));
+        self.refresh_sessions().await;
+        Ok(())
+    }
+
     /// View windows for selected session
     async fn view_windows(&mut self) {
-        if let Some(session) = self.get_selected_session() {
+        if let Some(session) = self.get_selected_session().filter(|s| !s.is_virtual()) {
             self.selected_session = Some(session.id.clone());
             self.view = View::Windows;
             self.window_index = 0;
+            self.marked_windows.clear();
             self.refresh_windows().await;
         }
     }
 
+    /// Prompt for a pattern to grep every session's scrollback with
+    fn start_grep(&mut self) {
+        if !matches!(self.view, View::Sessions | View::Grep) {
+            return;
+        }
+
+        self.input_mode = InputMode::Input {
+            prompt: "Grep all sessions:".to_string(),
+            purpose: InputPurpose::Grep,
+        };
+        self.input_buffer = self.grep_query.clone();
+        self.input_cursor = self.input_buffer.len();
+    }
+
+    /// Grep the scrollback of every listed session and show the matches
+    async fn run_grep(&mut self, query: String) {
+        let regex = match screen::scrollback::smart_case_regex(&query) {
+            Ok(regex) => regex,
+            Err(e) => {
+                self.error_message = Some(format!("Invalid pattern: {}", e));
+                return;
+            }
+        };
+
+        let report = screen::grep::grep(&self.config, &self.sessions, &regex, GREP_CONTEXT).await;
+
+        self.status_message = Some(format!(
+            "{} match(es) in {} window(s){}",
+            report.hits.len(),
+            report.windows,
+            if report.errors.is_empty() {
+                String::new()
+            } else {
+                format!(", {} failed", report.errors.len())
+            }
+        ));
+        self.grep_query = query;
+        self.grep_hits = report.hits;
+        self.grep_index = 0;
+        self.view = View::Grep;
+        self.focus = Focus::List;
+    }
+
+    /// Show the selected grep match in the preview of its session
+    ///
+    /// The preview captures the match's window, searches it for the grep
+    /// pattern and scrolls to the matching line.
+    async fn open_grep_hit(&mut self) {
+        let Some(hit) = self.grep_hits.get(self.grep_index).cloned() else {
+            return;
+        };
+
+        let row = self.session_rows.iter().position(|row| match row {
+            SessionRow::Session(idx) => self
+                .sessions
+                .get(*idx)
+                .is_some_and(|s| s.id == hit.session.id && s.host == hit.session.host),
+            SessionRow::Header(_) => false,
+        });
+        let Some(row) = row else {
+            self.error_message = Some(format!("'{}' is not listed", hit.session.name));
+            return;
+        };
+
+        self.view = View::Sessions;
+        self.session_index = row;
+        self.show_preview = true;
+        self.focus = Focus::Preview;
+        self.preview_follow = false;
+        self.preview_window = Some((hit.session.id.clone(), hit.window.number));
+        self.update_preview().await;
+
+        self.preview_search = Some(ScrollbackSearch {
+            query: self.grep_query.clone(),
+            ..Default::default()
+        });
+        let line = hit.hit.line;
+        let found = self.preview_search.as_mut().and_then(|search| {
+            search.update(&self.preview.lines);
+            search.jump_from(line)
+        });
+        self.scroll_preview_to_line(found.map_or(line, |m| m.line));
+    }
+
+    /// Open the dashboard
+    ///
+    /// From the Windows view it tiles the session's windows, otherwise every
+    /// visible session.
+    async fn view_dashboard(&mut self) {
+        let tiles = match self.view {
+            View::Windows => match self.windows_session() {
+                Some(session) => screen::dashboard::window_tiles(&session, &self.windows),
+                None => return,
+            },
+            View::Sessions => {
+                let sessions: Vec<Session> = self
+                    .session_rows
+                    .iter()
+                    .filter_map(|row| match row {
+                        SessionRow::Session(idx) => self.sessions.get(*idx).cloned(),
+                        SessionRow::Header(_) => None,
+                    })
+                    .collect();
+                screen::dashboard::session_tiles(&sessions)
+            }
+            _ => return,
+        };
+
+        self.dashboard_return = self.view;
+        self.dashboard = tiles;
+        self.dashboard_index = 0;
+        self.focus = Focus::List;
+        self.preview_zoomed = false;
+        self.view = View::Dashboard;
+        screen::dashboard::refresh(&self.config, &mut self.dashboard).await;
+    }
+
+    /// Attach to the selected dashboard tile (switching to its window first)
+    async fn attach_tile(&mut self) {
+        let Some(tile) = self.dashboard.get(self.dashboard_index).cloned() else {
+            return;
+        };
+        let session = tile.session;
+
+        if let Some(number) = tile.window.map(|w| w.number) {
+            let _ = screen::window::run(
+                &self.config,
+                session.host.as_deref(),
+                &session.id,
+                &WindowOp::Select(number),
+            )
+            .await;
+        }
+
+        self.record_history(&session.name, session.host.as_deref());
+        self.pending_attach = Some((session.id, session.host, false));
+        self.should_quit = true;
+    }
+
+    /// Session shown in the Windows view
+    fn windows_session(&self) -> Option<Session> {
+        self.get_selected_session()
+            .filter(|s| self.selected_session.as_ref() == Some(&s.id))
+            .cloned()
+    }
+
+    /// Start creating a window in the Windows view session
+    fn start_new_window(&mut self) {
+        if self.windows_session().is_some() {
+            self.input_mode = InputMode::Input {
+                prompt: "Window name:".to_string(),
+                purpose: InputPurpose::NewWindow,
+            };
+            self.input_buffer.clear();
+            self.input_cursor = 0;
+        }
+    }
+
+    /// Start renaming the highlighted window
+    fn start_rename_window(&mut self) {
+        if let Some(window) = self.windows.get(self.window_index) {
+            self.input_mode = InputMode::Input {
+                prompt: format!("Rename window {} to:", window.number),
+                purpose: InputPurpose::RenameWindow,
+            };
+            self.input_buffer = window.name.clone();
+            self.input_cursor = self.input_buffer.len();
+        }
+    }
+
+    /// Start prompting for a new number for the highlighted window
+    fn start_move_window(&mut self) {
+        if self.view != View::Windows {
+            return;
+        }
+
+        if let Some(window) = self.windows.get(self.window_index) {
+            self.input_mode = InputMode::Input {
+                prompt: format!("Move window {} to number:", window.number),
+                purpose: InputPurpose::MoveWindow,
+            };
+            self.input_buffer.clear();
+            self.input_cursor = 0;
+        }
+    }
+
+    /// Ask before killing the highlighted window
+    fn confirm_kill_window(&mut self) {
+        if let (Some(window), Some(session)) =
+            (self.windows.get(self.window_index), self.windows_session())
+        {
+            self.input_mode = InputMode::Confirm {
+                message: format!("Kill window {} ({})?", window.number, window.name),
+                action: ConfirmAction::KillWindow(Target::from_session(&session), window.number),
+            };
+        }
+    }
+
+    /// Swap the highlighted window with its neighbour
+    async fn shift_window(&mut self, forward: bool) {
+        if self.view != View::Windows {
+            return;
+        }
+
+        let Some(window) = self.windows.get(self.window_index) else {
+            return;
+        };
+        if let Some(number) =
+            screen::window::neighbour_number(&self.windows, self.window_index, forward)
+        {
+            self.run_window_op(WindowOp::Move(window.number, number))
+                .await;
+        }
+    }
+
+    /// Apply a window operation to the Windows view session and refresh
+    async fn run_window_op(&mut self, op: WindowOp) {
+        let Some(session) = self.windows_session() else {
+            return;
+        };
+
+        match screen::window::run(&self.config, session.host.as_deref(), &session.id, &op).await {
+            Ok(_) => {
+                self.status_message = Some(op.done_message());
+                self.refresh_windows().await;
+                // Keep the moved window highlighted
+                if let WindowOp::Move(_, number) = op {
+                    if let Some(pos) = self.windows.iter().position(|w| w.number == number) {
+                        self.window_index = pos;
+                    }
+                }
+            }
+            Err(e) => {
+                self.error_message = Some(format!("{:#}", e));
+            }
+        }
+    }
+
     /// Refresh windows list
     async fn refresh_windows(&mut self) {
-        if let Some(ref session) = self.selected_session {
-            match screen::local::list_windows(session).await {
+        if let Some(session) = self.windows_session() {
+            match screen::window::list(&self.config, session.host.as_deref(), &session.id).await {
                 Ok(windows) => {
                     self.windows = windows;
                     if self.window_index >= self.windows.len() {
@@ -764,9 +2364,16 @@ impl App {
 
     /// Switch to next host
     fn switch_host(&mut self) {
-        self.host_index = (self.host_index + 1) % self.hosts.len();
-        self.selected_host = self.hosts[self.host_index].clone();
-        // Refresh will be triggered by tick or manually
+        self.select_host((self.host_index + 1) % self.hosts.len());
+    }
+
+    /// Switch to a host by index
+    fn select_host(&mut self, index: usize) {
+        if index < self.hosts.len() {
+            self.host_index = index;
+            self.selected_host = self.hosts[index].clone();
+            // Refresh will be triggered by tick or manually
+        }
     }
 
     /// Refresh session list (local only for responsiveness)
@@ -783,12 +2390,34 @@ impl App {
             }
         }
 
+        screen::local::annotate_sessions(&mut all_sessions, self.config.integrations.git_status)
+            .await;
+
+        // Discovered projects without a running session
+        for project in &self.projects {
+            let name = project.session_name();
+            if !all_sessions.iter().any(|s| s.is_local() && s.name == name) {
+                let dir = project.root.to_string_lossy().into_owned();
+                let mut session = Session::project(name, dir.clone());
+                session.repo_root = project.root.join(".git").exists().then_some(dir);
+                all_sessions.push(session);
+            }
+        }
+
+        // Drop marks on local sessions that no longer exist
+        self.marked
+            .retain(|t| t.host.is_some() || all_sessions.iter().any(|s| t.is_session(s)));
+        self.unread
+            .retain(|t| t.host.is_some() || all_sessions.iter().any(|s| t.is_session(s)));
+        self.seen_output
+            .retain(|t, _| t.host.is_some() || all_sessions.iter().any(|s| t.is_session(s)));
+
         self.sessions = all_sessions;
         self.apply_filter();
 
         // Reset selection if out of bounds
-        if self.session_index >= self.filtered_sessions.len() {
-            self.session_index = self.filtered_sessions.len().saturating_sub(1);
+        if self.session_index >= self.session_rows.len() {
+            self.session_index = self.session_rows.len().saturating_sub(1);
         }
     }
 
@@ -804,6 +2433,10 @@ impl App {
                     // Remove old sessions from this host and add new ones
                     self.sessions
                         .retain(|s| s.host.as_ref() != Some(&host.name));
+                    self.marked.retain(|t| {
+                        t.host.as_ref() != Some(&host.name)
+                            || sessions.iter().any(|s| t.is_session(s))
+                    });
                     self.sessions.extend(sessions);
                 }
                 Err(_) => {
@@ -814,11 +2447,16 @@ impl App {
 
         self.apply_filter();
 
-        if self.session_index >= self.filtered_sessions.len() {
-            self.session_index = self.filtered_sessions.len().saturating_sub(1);
+        if self.session_index >= self.session_rows.len() {
+            self.session_index = self.session_rows.len().saturating_sub(1);
         }
     }
 
+    /// Discover projects under the configured roots
+    fn load_projects(&mut self) {
+        self.projects = crate::config::project::discover_projects(&self.config.projects);
+    }
+
     /// Load templates
     fn load_templates(&mut self) {
         match crate::config::templates::list_templates(&self.config) {
@@ -832,42 +2470,107 @@ impl App {
     }
 
     /// Apply search filter
+    ///
+    /// Sessions are ordered by frecency; with a query, by match score
+    /// blended with frecency.
     fn apply_filter(&mut self) {
-        if self.search_query.is_empty() {
-            self.filtered_sessions = (0..self.sessions.len()).collect();
-        } else {
-            self.filtered_sessions = self
-                .sessions
-                .iter()
-                .enumerate()
-                .filter_map(|(i, session)| {
-                    let score = self.matcher.fuzzy_match(&session.name, &self.search_query);
-                    score.map(|_| i)
-                })
-                .collect();
+        let filter = Filter::parse(&self.search_query);
+        self.search_highlights.clear();
+
+        let mut scored: Vec<(usize, f64)> = Vec::new();
+        for (i, session) in self.sessions.iter().enumerate() {
+            let frecency = self.history.frecency(session);
+            if filter.is_empty() {
+                scored.push((i, frecency));
+            } else if let Some(m) = filter.matches(session, &self.matcher) {
+                scored.push((i, m.score as f64 + FRECENCY_WEIGHT * frecency.ln_1p()));
+                if !m.name_indices.is_empty() {
+                    self.search_highlights.insert(i, m.name_indices);
+                }
+            }
         }
 
+        // Stable sort keeps screen order for ties
+        let sort_by = self.config.ui.sort_by;
+        scored.sort_by(|a, b| match sort_by {
+            SortKey::Frecency => b.1.total_cmp(&a.1),
+            key => key.compare(&self.sessions[a.0], &self.sessions[b.0]),
+        });
+        self.filtered_sessions = scored.into_iter().map(|(i, _)| i).collect();
+        self.rebuild_rows();
+
         // Reset selection if out of bounds
-        if self.session_index >= self.filtered_sessions.len() {
-            self.session_index = 0;
+        if self.session_index >= self.session_rows.len() {
+            self.select_first_session();
+        }
+    }
+
+    /// Rebuild groups and list rows from the filtered sessions
+    fn rebuild_rows(&mut self) {
+        let group_by = self.config.ui.group_by;
+        self.session_groups =
+            screen::types::group_sessions(&self.sessions, &self.filtered_sessions, group_by);
+
+        self.session_rows.clear();
+        for (i, group) in self.session_groups.iter().enumerate() {
+            if group_by != GroupBy::None {
+                self.session_rows.push(SessionRow::Header(i));
+                if self.collapsed_groups.contains(&group.label) {
+                    continue;
+                }
+            }
+            self.session_rows
+                .extend(group.sessions.iter().map(|&idx| SessionRow::Session(idx)));
         }
     }
 
     /// Update preview content
     async fn update_preview(&mut self) {
         if let Some(session) = self.get_selected_session() {
+            if session.is_virtual() {
+                self.preview = Preview::new();
+                return;
+            }
+
             let session_id = session.id.clone();
+            let name = session.name.clone();
             let host = session.host.clone();
+            let window = self.preview_window_number();
 
             let result = if let Some(ref host) = host {
-                screen::remote::get_preview(&self.config, host, &session_id, None).await
+                screen::remote::get_preview(&self.config, host, &session_id, window).await
             } else {
-                screen::local::get_preview(&session_id, None).await
+                screen::local::get_preview(&session_id, window).await
             };
 
             match result {
-                Ok(preview) => {
+                Ok(mut preview) => {
+                    // Keep the scroll position and highlight changes while the
+                    // same window refreshes
+                    let source = (session_id.clone(), window);
+                    let offset = if self.preview_source.as_ref() == Some(&source) {
+                        preview.diff_against(&self.preview);
+                        self.preview.scroll_offset
+                    } else {
+                        0
+                    };
+                    let target = Target {
+                        id: session_id.clone(),
+                        name,
+                        host: host.clone(),
+                    };
+                    let fingerprint = window.is_none().then(|| preview.fingerprint());
+                    self.mark_seen(target, fingerprint);
                     self.preview = preview;
+                    self.preview.scroll_offset =
+                        offset.min(self.preview.lines.len().saturating_sub(1));
+                    self.preview_source = Some(source);
+                    if let Some(ref mut search) = self.preview_search {
+                        search.update(&self.preview.lines);
+                    }
+                    if self.preview_follow {
+                        self.scroll_preview_to_end();
+                    }
                 }
                 Err(e) => {
                     // Log preview error but don't show to user (too noisy)
@@ -877,6 +2580,134 @@ impl App {
         }
     }
 
+    /// Check the next session for output the user hasn't seen
+    ///
+    /// One session is captured per tick, round-robin, so the cost stays flat
+    /// however many sessions are listed. Remote sessions are captured on a
+    /// background task, one at a time, and the result is picked up on a later
+    /// tick so ssh never holds up input or redraws.
+    async fn scan_unread(&mut self) {
+        if let Some(task) = self.remote_scan.take_if(|task| task.is_finished()) {
+            if let Ok((target, Some(fingerprint))) = task.await {
+                self.record_scan(target, fingerprint);
+            }
+        }
+
+        let candidates: Vec<Target> = self
+            .sessions
+            .iter()
+            .filter(|s| !s.is_virtual())
+            .map(Target::from_session)
+            .collect();
+        if candidates.is_empty() {
+            return;
+        }
+
+        self.unread_cursor = (self.unread_cursor + 1) % candidates.len();
+        let target = candidates[self.unread_cursor].clone();
+
+        // The previewed session is kept up to date by the preview itself
+        let previewed = self.show_preview
+            && self
+                .get_selected_session()
+                .is_some_and(|s| target.is_session(s));
+        if previewed {
+            return;
+        }
+
+        match target.host.clone() {
+            Some(host) => {
+                if self.remote_scan.is_some() {
+                    return;
+                }
+                let config = self.config.clone();
+                self.remote_scan = Some(tokio::spawn(async move {
+                    let fingerprint = screen::remote::get_preview(&config, &host, &target.id, None)
+                        .await
+                        .ok()
+                        .map(|preview| preview.fingerprint());
+                    (target, fingerprint)
+                }));
+            }
+            None => {
+                if let Ok(preview) = screen::local::get_preview(&target.id, None).await {
+                    self.record_scan(target, preview.fingerprint());
+                }
+            }
+        }
+    }
+
+    /// Compare a scanned session's output with what the user last saw
+    ///
+    /// The first scan of a session only records a baseline.
+    fn record_scan(&mut self, target: Target, fingerprint: u64) {
+        match self.seen_output.get(&target) {
+            Some(seen) if *seen != fingerprint => {
+                self.unread.insert(target);
+            }
+            Some(_) => {}
+            None => {
+                self.seen_output.insert(target, fingerprint);
+            }
+        }
+    }
+
+    /// Record that the user has seen a session's output
+    ///
+    /// The fingerprint is only known when the default window was captured;
+    /// otherwise the next scan takes a fresh baseline.
+    fn mark_seen(&mut self, target: Target, fingerprint: Option<u64>) {
+        self.unread.remove(&target);
+        match fingerprint {
+            Some(fingerprint) => self.seen_output.insert(target, fingerprint),
+            None => self.seen_output.remove(&target),
+        };
+    }
+
+    /// Prompt for the name to save the selected session as a template under
+    fn start_save_template(&mut self) {
+        if self.view != View::Sessions {
+            return;
+        }
+        let Some(session) = self
+            .get_selected_session()
+            .filter(|s| s.is_local() && !s.is_virtual())
+        else {
+            return;
+        };
+
+        self.input_buffer = session.name.clone();
+        self.input_cursor = self.input_buffer.len();
+        self.input_mode = InputMode::Input {
+            prompt: "Save as template:".to_string(),
+            purpose: InputPurpose::SaveTemplate,
+        };
+    }
+
+    /// Save the selected session's live layout as a template
+    async fn save_template(&mut self, name: String) {
+        let Some(session) = self.get_selected_session().cloned() else {
+            return;
+        };
+
+        let result =
+            crate::config::templates::save_from_session(&self.config, &session, &name, false).await;
+
+        match result {
+            Ok((template, _)) => {
+                self.status_message = Some(format!(
+                    "Saved template '{}' ({} windows)",
+                    name,
+                    template.windows.len()
+                ));
+                self.load_templates();
+            }
+            Err(e) => {
+                self.error_message = Some(format!("Failed to save template: {}", e));
+            }
+        }
+    }
+
     /// Create session from template
     async fn create_from_template(&mut self) {
         if let Some(template) = self.templates.get(self.template_index).cloned() {
@@ -898,8 +2729,14 @@ impl App {
             .await
             {
                 Ok(_) => {
-                    self.status_message =
-                        Some(format!("Created session from template '{}'", template.name));
+                    self.record_history(session_name, None);
+                    self.status_message = Some(match template.warnings.len() {
+                        0 => format!("Created session from template '{}'", template.name),
+                        n => format!(
+                            "Created session from template '{}' ({} warnings, see Template Details)",
+                            template.name, n
+                        ),
+                    });
                     self.view = View::Sessions;
                     self.refresh_sessions().await;
                 }
@@ -916,6 +2753,13 @@ impl App {
             InputMode::Search => {
                 self.search_query.push(c);
                 self.apply_filter();
+                self.select_first_session();
+            }
+            InputMode::PreviewSearch => {
+                if let Some(ref mut search) = self.preview_search {
+                    search.query.push(c);
+                }
+                self.update_preview_search();
             }
             InputMode::Input { .. } => {
                 self.input_buffer.insert(self.input_cursor, c);
@@ -931,6 +2775,13 @@ impl App {
             InputMode::Search => {
                 self.search_query.pop();
                 self.apply_filter();
+                self.select_first_session();
+            }
+            InputMode::PreviewSearch => {
+                if let Some(ref mut search) = self.preview_search {
+                    search.query.pop();
+                }
+                self.update_preview_search();
             }
             InputMode::Input { .. } => {
                 if self.input_cursor > 0 {
@@ -958,6 +2809,17 @@ impl App {
                 // Keep search active, just stay in filter mode
                 self.input_mode = InputMode::Normal;
             }
+            InputMode::PreviewSearch => {
+                // Keep the matches highlighted for n/N
+                self.input_mode = InputMode::Normal;
+                if self
+                    .preview_search
+                    .as_ref()
+                    .is_some_and(|s| s.query.is_empty())
+                {
+                    self.preview_search = None;
+                }
+            }
             InputMode::Input { purpose, .. } => {
                 let value = self.input_buffer.clone();
                 let purpose = purpose.clone();
@@ -978,6 +2840,7 @@ impl App {
                         .await
                         {
                             Ok(_) => {
+                                self.record_history(&value, None);
                                 self.status_message = Some(format!("Created session '{}'", value));
                                 self.refresh_sessions().await;
                             }
@@ -1003,23 +2866,39 @@ impl App {
                         }
                     }
                     InputPurpose::NewWindow => {
-                        if let Some(ref session) = self.selected_session {
-                            match screen::local::create_window(session, Some(&value)).await {
-                                Ok(_) => {
-                                    self.status_message =
-                                        Some(format!("Created window '{}'", value));
-                                    self.refresh_windows().await;
-                                }
-                                Err(e) => {
-                                    self.error_message =
-                                        Some(format!("Failed to create window: {}", e));
-                                }
-                            }
+                        self.run_window_op(WindowOp::New(Some(value))).await;
+                    }
+                    InputPurpose::BulkRename => self.confirm_bulk(BulkOp::Rename(value)),
+                    InputPurpose::SendCommand => {
+                        let targets = self.bulk_targets();
+                        if targets.len() > 1 {
+                            self.confirm_bulk(BulkOp::Command(value));
+                        } else {
+                            self.run_bulk(BulkOp::Command(value), targets).await;
                         }
                     }
+                    InputPurpose::Grep => self.run_grep(value).await,
+                    InputPurpose::SendText => self.send_text(value).await,
+                    InputPurpose::Broadcast => self.broadcast_text(value).await,
+                    InputPurpose::ScreenCommand => self.run_console_command(value).await,
+                    InputPurpose::SaveTemplate => self.save_template(value).await,
                     InputPurpose::RenameWindow => {
-                        // TODO: Implement window rename
+                        if let Some(window) = self.windows.get(self.window_index) {
+                            let op = WindowOp::Rename(window.number, value);
+                            self.run_window_op(op).await;
+                        }
                     }
+                    InputPurpose::MoveWindow => match value.trim().parse::<usize>() {
+                        Ok(number) => {
+                            if let Some(window) = self.windows.get(self.window_index) {
+                                let op = WindowOp::Move(window.number, number);
+                                self.run_window_op(op).await;
+                            }
+                        }
+                        Err(_) => {
+                            self.error_message = Some(format!("Invalid window number '{}'", value));
+                        }
+                    },
                     InputPurpose::TemplateVariable(_) => {
                         // TODO: Implement template variable handling
                     }
@@ -1092,6 +2971,7 @@ impl App {
         self.input_mode = InputMode::Normal;
         self.input_buffer.clear();
         self.input_cursor = 0;
+        self.history_cursor = None;
         self.new_host = None;
     }
 
@@ -1175,20 +3055,4 @@ impl App {
             self.status_message = Some(format!("Added host '{}'", name));
         }
     }
-
-    /// Get sessions for current host filter
-    pub fn get_visible_sessions(&self) -> Vec<&Session> {
-        self.filtered_sessions
-            .iter()
-            .filter_map(|&idx| self.sessions.get(idx))
-            .filter(|s| {
-                match (&self.selected_host, &s.host) {
-                    (None, None) => true,           // Local only
-                    (Some(h), Some(sh)) => h == sh, // Matching host
-                    (None, Some(_)) => true,        // Show all when local selected
-                    _ => false,
-                }
-            })
-            .collect()
-    }
 }
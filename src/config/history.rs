@@ -0,0 +1,254 @@
+//! Attach history
+//!
+//! Records every attach/create in a small state file so sessions can be
+//! ranked by frecency (frequency weighted by recency).
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use super::Settings;
+use crate::screen::Session;
+
+/// Maximum number of entries kept in the history file
+const MAX_ENTRIES: usize = 500;
+
+/// A single history entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// Session name
+    pub name: String,
+
+    /// Host the session is on (None for local)
+    pub host: Option<String>,
+
+    /// Number of times the session was used
+    pub count: u32,
+
+    /// When the session was last used
+    pub last_used: DateTime<Local>,
+}
+
+impl HistoryEntry {
+    /// Frecency score at a given time
+    pub fn frecency(&self, now: DateTime<Local>) -> f64 {
+        let age = now.signed_duration_since(self.last_used);
+
+        let weight = if age.num_hours() < 1 {
+            4.0
+        } else if age.num_days() < 1 {
+            2.0
+        } else if age.num_weeks() < 1 {
+            0.5
+        } else {
+            0.25
+        };
+
+        self.count as f64 * weight
+    }
+}
+
+/// Persistent attach history
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct History {
+    /// Recorded sessions
+    #[serde(default)]
+    pub entries: Vec<HistoryEntry>,
+
+    /// File the history was loaded from
+    #[serde(skip)]
+    path: Option<PathBuf>,
+}
+
+impl History {
+    /// Default history file path
+    pub fn default_path() -> PathBuf {
+        Settings::state_dir().join("history.toml")
+    }
+
+    /// Load history from the default location
+    pub fn load_default() -> Self {
+        Self::load(&Self::default_path()).unwrap_or_default()
+    }
+
+    /// Load history from a file (missing files give an empty history)
+    pub fn load(path: &Path) -> Result<Self> {
+        let mut history = if path.exists() {
+            let content = std::fs::read_to_string(path)
+                .context(format!("Failed to read history file: {:?}", path))?;
+            toml::from_str(&content).context("Failed to parse history file")?
+        } else {
+            Self::default()
+        };
+
+        history.path = Some(path.to_path_buf());
+        Ok(history)
+    }
+
+    /// Save history back to the file it was loaded from
+    pub fn save(&self) -> Result<()> {
+        let path = match &self.path {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let content = toml::to_string_pretty(self)?;
+        std::fs::write(path, content)?;
+
+        Ok(())
+    }
+
+    /// Record a use of a session and save
+    ///
+    /// Accepts either a session name or a full `PID.name` identifier.
+    pub fn record(&mut self, session: &str, host: Option<&str>) -> Result<()> {
+        self.touch(strip_pid(session), host, Local::now());
+        self.save()
+    }
+
+    /// Record a use of a session at a given time
+    pub fn touch(&mut self, name: &str, host: Option<&str>, now: DateTime<Local>) {
+        if let Some(entry) = self
+            .entries
+            .iter_mut()
+            .find(|e| e.name == name && e.host.as_deref() == host)
+        {
+            entry.count = entry.count.saturating_add(1);
+            entry.last_used = now;
+        } else {
+            self.entries.push(HistoryEntry {
+                name: name.to_string(),
+                host: host.map(String::from),
+                count: 1,
+                last_used: now,
+            });
+        }
+
+        // Most recent first, dropping the oldest entries
+        self.entries.sort_by_key(|e| std::cmp::Reverse(e.last_used));
+        self.entries.truncate(MAX_ENTRIES);
+    }
+
+    /// Frecency score for a session (0 if never used)
+    pub fn frecency(&self, session: &Session) -> f64 {
+        let now = Local::now();
+        self.entries
+            .iter()
+            .find(|e| e.name == session.name && e.host == session.host)
+            .map(|e| e.frecency(now))
+            .unwrap_or(0.0)
+    }
+
+    /// Most recently used session other than `current`
+    pub fn previous(&self, current: Option<&str>) -> Option<&HistoryEntry> {
+        self.entries
+            .iter()
+            .filter(|e| e.host.is_some() || Some(e.name.as_str()) != current)
+            .max_by_key(|e| e.last_used)
+    }
+
+    /// Sort sessions by frecency, most used first
+    ///
+    /// The sort is stable, so sessions without history keep their order.
+    pub fn sort_sessions(&self, sessions: &mut [Session]) {
+        sessions.sort_by(|a, b| self.frecency(b).total_cmp(&self.frecency(a)));
+    }
+}
+
+/// Strip the PID prefix from a `PID.name` session identifier
+pub fn strip_pid(session: &str) -> &str {
+    match session.split_once('.') {
+        Some((pid, name)) if !pid.is_empty() && pid.chars().all(|c| c.is_ascii_digit()) => name,
+        _ => session,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::screen::SessionStatus;
+    use chrono::Duration;
+    use tempfile::tempdir;
+
+    fn session(name: &str) -> Session {
+        Session::new(
+            format!("1.{}", name),
+            name.to_string(),
+            1,
+            SessionStatus::Detached,
+        )
+    }
+
+    #[test]
+    fn test_frecency_weights_recency() {
+        let now = Local::now();
+        let recent = HistoryEntry {
+            name: "a".to_string(),
+            host: None,
+            count: 2,
+            last_used: now,
+        };
+        let old = HistoryEntry {
+            last_used: now - Duration::days(30),
+            count: 10,
+            ..recent.clone()
+        };
+
+        assert_eq!(recent.frecency(now), 8.0);
+        assert_eq!(old.frecency(now), 2.5);
+    }
+
+    #[test]
+    fn test_touch_and_sort() {
+        let mut history = History::default();
+        let now = Local::now();
+        history.touch("api", None, now - Duration::days(3));
+        history.touch("web", None, now);
+        history.touch("web", None, now);
+
+        let mut sessions = vec![session("idle"), session("api"), session("web")];
+        history.sort_sessions(&mut sessions);
+
+        let names: Vec<_> = sessions.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["web", "api", "idle"]);
+    }
+
+    #[test]
+    fn test_previous_skips_current() {
+        let mut history = History::default();
+        let now = Local::now();
+        history.touch("api", None, now - Duration::minutes(5));
+        history.touch("web", None, now);
+
+        assert_eq!(history.previous(None).unwrap().name, "web");
+        assert_eq!(history.previous(Some("web")).unwrap().name, "api");
+    }
+
+    #[test]
+    fn test_strip_pid() {
+        assert_eq!(strip_pid("12345.dev"), "dev");
+        assert_eq!(strip_pid("12345.app.v2"), "app.v2");
+        assert_eq!(strip_pid("app.v2"), "app.v2");
+        assert_eq!(strip_pid("dev"), "dev");
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("state").join("history.toml");
+
+        let mut history = History::load(&path).unwrap();
+        history.record("api", Some("prod")).unwrap();
+
+        let loaded = History::load(&path).unwrap();
+        assert_eq!(loaded.entries.len(), 1);
+        assert_eq!(loaded.entries[0].name, "api");
+        assert_eq!(loaded.entries[0].host.as_deref(), Some("prod"));
+        assert_eq!(loaded.entries[0].count, 1);
+    }
+}
@@ -0,0 +1,114 @@
+//! Prompt history
+//!
+//! Remembers what was typed into a TUI prompt (text sent to windows, screen
+//! commands) so it can be recalled with Up and Down. Each prompt keeps one
+//! entry per line in a file in the state directory.
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+use super::Settings;
+
+/// Maximum number of entries kept per prompt
+const MAX_ENTRIES: usize = 200;
+
+/// Entries typed into one prompt, oldest first
+#[derive(Debug, Clone, Default)]
+pub struct InputHistory {
+    /// Entries, oldest first
+    pub entries: Vec<String>,
+
+    /// File the history was loaded from
+    path: Option<PathBuf>,
+}
+
+impl InputHistory {
+    /// Load the history of a named prompt from the state directory
+    pub fn load_default(name: &str) -> Self {
+        let path = Settings::state_dir().join(format!("{}_history", name));
+        Self::load(&path).unwrap_or_default()
+    }
+
+    /// Load history from a file (missing files give an empty history)
+    pub fn load(path: &Path) -> Result<Self> {
+        let entries = if path.exists() {
+            std::fs::read_to_string(path)
+                .context(format!("Failed to read history file: {:?}", path))?
+                .lines()
+                .filter(|line| !line.is_empty())
+                .map(String::from)
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        Ok(Self {
+            entries,
+            path: Some(path.to_path_buf()),
+        })
+    }
+
+    /// Add an entry and save
+    ///
+    /// Repeating an earlier entry moves it to the end.
+    pub fn push(&mut self, entry: &str) -> Result<()> {
+        if entry.is_empty() || entry.contains('\n') {
+            return Ok(());
+        }
+
+        self.entries.retain(|e| e != entry);
+        self.entries.push(entry.to_string());
+        if self.entries.len() > MAX_ENTRIES {
+            self.entries.drain(..self.entries.len() - MAX_ENTRIES);
+        }
+
+        self.save()
+    }
+
+    /// Entry `back` steps from the newest (0 is the newest)
+    pub fn recall(&self, back: usize) -> Option<&str> {
+        let index = self.entries.len().checked_sub(back + 1)?;
+        self.entries.get(index).map(String::as_str)
+    }
+
+    /// Save the history back to the file it was loaded from
+    fn save(&self) -> Result<()> {
+        let path = match &self.path {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let mut content = self.entries.join("\n");
+        content.push('\n');
+        std::fs::write(path, content)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_push_recall_roundtrip() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("send_history");
+
+        let mut history = InputHistory::load(&path).unwrap();
+        history.push("make test").unwrap();
+        history.push("git status").unwrap();
+        history.push("make test").unwrap();
+
+        let loaded = InputHistory::load(&path).unwrap();
+        assert_eq!(loaded.entries, vec!["git status", "make test"]);
+        assert_eq!(loaded.recall(0), Some("make test"));
+        assert_eq!(loaded.recall(1), Some("git status"));
+        assert_eq!(loaded.recall(2), None);
+    }
+}
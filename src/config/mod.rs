@@ -2,8 +2,13 @@
 //!
 //! Handles loading and parsing configuration files.
 
+pub mod history;
 pub mod hosts;
+pub mod input_history;
+pub mod project;
 pub mod settings;
+pub mod snapshot;
 pub mod templates;
+pub mod tmux;
 
 pub use settings::Settings;
@@ -0,0 +1,339 @@
+//! Per-project `.sesh` files
+//!
+//! A `.sesh` file in a project directory marks it as a sesh project. It can be
+//! empty, hold an inline template, or reference a named template with variables.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use super::settings::ProjectSettings;
+use super::templates::{self, Template};
+use super::Settings;
+use crate::integrations::{git, shell};
+use crate::screen;
+
+/// Name of the per-project file
+pub const PROJECT_FILE: &str = ".sesh";
+
+/// Contents of a `.sesh` file
+#[derive(Debug, Clone, Default)]
+pub enum ProjectFile {
+    /// Empty marker file
+    #[default]
+    Marker,
+    /// Reference to a template in the templates directory
+    Reference(TemplateReference),
+    /// Template defined inline
+    Inline(Template),
+}
+
+/// Reference to a named template
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplateReference {
+    /// Template name
+    pub template: String,
+
+    /// Session name override
+    pub name: Option<String>,
+
+    /// Template variables
+    #[serde(default)]
+    pub variables: HashMap<String, String>,
+}
+
+/// A project directory with its optional `.sesh` file
+#[derive(Debug, Clone)]
+pub struct Project {
+    /// Project root directory
+    pub root: PathBuf,
+
+    /// Parsed `.sesh` file (None if the directory has no marker)
+    pub file: Option<ProjectFile>,
+}
+
+impl Project {
+    /// Locate the project for a directory
+    ///
+    /// Looks for a `.sesh` file in `dir`, then in its git root. Without a
+    /// marker, the git root (or `dir` itself) is used as the project root.
+    pub fn find(dir: &Path) -> Result<Self> {
+        let git_root = git::get_repo_root(dir).map(PathBuf::from);
+
+        let candidates = std::iter::once(dir.to_path_buf()).chain(git_root.clone());
+        for candidate in candidates {
+            if candidate.join(PROJECT_FILE).is_file() {
+                return Self::load(candidate);
+            }
+        }
+
+        Ok(Self {
+            root: git_root.unwrap_or_else(|| dir.to_path_buf()),
+            file: None,
+        })
+    }
+
+    /// Load the project rooted at `root`, reading its `.sesh` file if present
+    pub fn load(root: PathBuf) -> Result<Self> {
+        let path = root.join(PROJECT_FILE);
+        let file = if path.is_file() {
+            Some(load_project_file(&path)?)
+        } else {
+            None
+        };
+
+        Ok(Self { root, file })
+    }
+
+    /// Session name for this project
+    pub fn session_name(&self) -> String {
+        let explicit = match &self.file {
+            Some(ProjectFile::Reference(reference)) => reference.name.clone(),
+            Some(ProjectFile::Inline(template)) if !template.name.is_empty() => {
+                Some(template.name.clone())
+            }
+            _ => None,
+        };
+
+        explicit
+            .or_else(|| shell::get_suggested_session_name(&self.root))
+            .unwrap_or_else(|| "sesh".to_string())
+    }
+
+    /// Resolve the template and variables to create this project's session
+    ///
+    /// Returns None for projects without a template (plain marker or no file).
+    /// The template root defaults to the project directory.
+    pub fn resolve_template(
+        &self,
+        config: &Settings,
+    ) -> Result<Option<(Template, HashMap<String, String>)>> {
+        let (mut template, mut variables) = match &self.file {
+            Some(ProjectFile::Reference(reference)) => (
+                templates::load_template(config, &reference.template)?,
+                reference.variables.clone(),
+            ),
+            Some(ProjectFile::Inline(template)) => (template.clone(), HashMap::new()),
+            Some(ProjectFile::Marker) | None => return Ok(None),
+        };
+
+        if template.root.is_none() {
+            template.root = Some(self.root.to_string_lossy().into_owned());
+        }
+
+        for (key, var) in &template.variables {
+            if let Some(ref default) = var.default {
+                variables
+                    .entry(key.clone())
+                    .or_insert_with(|| default.clone());
+            }
+        }
+
+        Ok(Some((template, variables)))
+    }
+
+    /// Create a detached local session for this project
+    ///
+    /// Uses the project's template when it has one, otherwise starts a plain
+    /// session in the project root.
+    pub async fn create_session(&self, config: &Settings, session_name: &str) -> Result<()> {
+        if let Some((template, variables)) = self.resolve_template(config)? {
+            templates::create_from_template(config, &template, session_name, &variables).await
+        } else {
+            let root = self.root.to_string_lossy();
+            screen::local::create_session(
+                session_name,
+                Some(root.as_ref()),
+                config.screen.default_shell.as_deref(),
+            )
+            .await
+        }
+    }
+}
+
+/// Discover projects under the configured search roots
+///
+/// A directory is a project if it contains `.git` or a `.sesh` file; the scan
+/// does not descend into projects or hidden directories.
+pub fn discover_projects(settings: &ProjectSettings) -> Vec<Project> {
+    let mut projects = Vec::new();
+
+    for root in &settings.roots {
+        let root = PathBuf::from(shellexpand::tilde(root).as_ref());
+        scan_dir(&root, settings.max_depth, &mut projects);
+    }
+
+    projects.sort_by(|a, b| a.root.cmp(&b.root));
+    projects.dedup_by(|a, b| a.root == b.root);
+
+    projects
+}
+
+/// Recursively scan a directory for projects
+fn scan_dir(dir: &Path, depth: usize, projects: &mut Vec<Project>) {
+    if dir.join(".git").exists() || dir.join(PROJECT_FILE).is_file() {
+        // Unparsable .sesh files still mark a project, just without a template
+        let project = Project::load(dir.to_path_buf()).unwrap_or_else(|_| Project {
+            root: dir.to_path_buf(),
+            file: None,
+        });
+        projects.push(project);
+        return;
+    }
+
+    if depth == 0 {
+        return;
+    }
+
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let hidden = entry.file_name().to_string_lossy().starts_with('.');
+        if !hidden && path.is_dir() {
+            scan_dir(&path, depth - 1, projects);
+        }
+    }
+}
+
+/// Load and parse a `.sesh` file
+pub fn load_project_file(path: &Path) -> Result<ProjectFile> {
+    let content = std::fs::read_to_string(path)
+        .context(format!("Failed to read project file: {:?}", path))?;
+    parse_project_file(&content).context(format!("Failed to parse project file: {:?}", path))
+}
+
+/// Parse the contents of a `.sesh` file
+pub fn parse_project_file(content: &str) -> Result<ProjectFile> {
+    let mut value: serde_yaml::Value = serde_yaml::from_str(content)?;
+
+    // Empty (or comment-only) files are plain markers
+    if value.is_null() {
+        return Ok(ProjectFile::Marker);
+    }
+
+    let mapping = value
+        .as_mapping_mut()
+        .ok_or_else(|| anyhow::anyhow!("expected a YAML mapping"))?;
+
+    if mapping.contains_key("template") {
+        let reference: TemplateReference = serde_yaml::from_value(value)?;
+        return Ok(ProjectFile::Reference(reference));
+    }
+
+    // Inline templates may omit the name; the directory name is used instead
+    if !mapping.contains_key("name") {
+        mapping.insert("name".into(), String::new().into());
+    }
+
+    let template: Template = serde_yaml::from_value(value)?;
+    Ok(ProjectFile::Inline(template))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_parse_empty_marker() {
+        assert!(matches!(
+            parse_project_file("").unwrap(),
+            ProjectFile::Marker
+        ));
+        assert!(matches!(
+            parse_project_file("# just a marker\n").unwrap(),
+            ProjectFile::Marker
+        ));
+    }
+
+    #[test]
+    fn test_parse_template_reference() {
+        let yaml = r#"
+template: webdev
+name: shop
+variables:
+  PROJECT_NAME: shop
+"#;
+        match parse_project_file(yaml).unwrap() {
+            ProjectFile::Reference(reference) => {
+                assert_eq!(reference.template, "webdev");
+                assert_eq!(reference.name.as_deref(), Some("shop"));
+                assert_eq!(reference.variables["PROJECT_NAME"], "shop");
+            }
+            other => panic!("expected reference, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_inline_template() {
+        let yaml = r#"
+windows:
+  - name: editor
+    command: nvim .
+  - name: shell
+"#;
+        match parse_project_file(yaml).unwrap() {
+            ProjectFile::Inline(template) => {
+                assert!(template.name.is_empty());
+                assert_eq!(template.windows.len(), 2);
+            }
+            other => panic!("expected inline template, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_find_project_with_inline_template() {
+        let dir = tempdir().unwrap();
+        let root = dir.path().join("myapp");
+        std::fs::create_dir(&root).unwrap();
+        std::fs::write(root.join(PROJECT_FILE), "windows:\n  - name: shell\n").unwrap();
+
+        let project = Project::find(&root).unwrap();
+        assert_eq!(project.root, root);
+        assert_eq!(project.session_name(), "myapp");
+
+        let (template, _) = project
+            .resolve_template(&Settings::default())
+            .unwrap()
+            .unwrap();
+        assert_eq!(template.root, Some(root.to_string_lossy().into_owned()));
+    }
+
+    #[test]
+    fn test_discover_projects() {
+        let dir = tempdir().unwrap();
+        let base = dir.path();
+        std::fs::create_dir_all(base.join("api/.git")).unwrap();
+        std::fs::create_dir_all(base.join("api/nested/.git")).unwrap();
+        std::fs::create_dir_all(base.join("clients/web")).unwrap();
+        std::fs::write(base.join("clients/web").join(PROJECT_FILE), "").unwrap();
+        std::fs::create_dir_all(base.join("a/b/c/deep/.git")).unwrap();
+        std::fs::create_dir_all(base.join(".hidden/.git")).unwrap();
+
+        let settings = ProjectSettings {
+            roots: vec![base.to_string_lossy().into_owned()],
+            max_depth: 2,
+        };
+        let projects = discover_projects(&settings);
+        let roots: Vec<_> = projects.iter().map(|p| p.root.clone()).collect();
+
+        assert_eq!(roots, vec![base.join("api"), base.join("clients/web")]);
+        assert!(matches!(projects[1].file, Some(ProjectFile::Marker)));
+    }
+
+    #[test]
+    fn test_find_project_without_marker() {
+        let dir = tempdir().unwrap();
+        let project = Project::find(dir.path()).unwrap();
+        assert!(project.file.is_none());
+        assert!(project
+            .resolve_template(&Settings::default())
+            .unwrap()
+            .is_none());
+    }
+}
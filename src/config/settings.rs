@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
 use super::hosts::HostConfig;
+use crate::screen::{GroupBy, SortKey};
 
 /// Main settings structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,6 +29,9 @@ pub struct Settings {
     /// Keybindings
     pub keybindings: KeyBindings,
 
+    /// Project discovery settings
+    pub projects: ProjectSettings,
+
     /// Configured SSH hosts
     #[serde(default)]
     pub hosts: Vec<HostConfig>,
@@ -41,10 +45,22 @@ pub struct UiSettings {
     pub theme: String,
     /// Show preview panel
     pub show_preview: bool,
-    /// Number of preview lines
+    /// Minimum number of lines shown in the preview panel
     pub preview_lines: usize,
+    /// Where the preview panel is placed
+    pub preview_position: PreviewPosition,
+    /// Share of the content area used by the preview panel (percent)
+    pub preview_size: u16,
+    /// Show line numbers in the preview
+    pub preview_line_numbers: bool,
+    /// Check other sessions for new output and mark them unread
+    pub unread_markers: bool,
     /// Refresh interval in milliseconds
     pub refresh_interval_ms: u32,
+    /// Session list sort order
+    pub sort_by: SortKey,
+    /// Session list grouping
+    pub group_by: GroupBy,
 }
 
 impl Default for UiSettings {
@@ -53,7 +69,40 @@ impl Default for UiSettings {
             theme: "dark".to_string(),
             show_preview: true,
             preview_lines: 8,
+            preview_position: PreviewPosition::Bottom,
+            preview_size: 40,
+            preview_line_numbers: true,
+            unread_markers: true,
             refresh_interval_ms: 1000,
+            sort_by: SortKey::Frecency,
+            group_by: GroupBy::Host,
+        }
+    }
+}
+
+/// Placement of the preview panel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum PreviewPosition {
+    /// Below the list
+    #[default]
+    Bottom,
+    /// Right of the list
+    Right,
+}
+
+impl PreviewPosition {
+    /// All positions in cycle order
+    pub fn all() -> [PreviewPosition; 2] {
+        [PreviewPosition::Bottom, PreviewPosition::Right]
+    }
+}
+
+impl std::fmt::Display for PreviewPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PreviewPosition::Bottom => write!(f, "bottom"),
+            PreviewPosition::Right => write!(f, "right"),
         }
     }
 }
@@ -92,6 +141,10 @@ pub struct ScreenSettings {
     pub attach_mode: String,
     /// Terminal to use for spawn mode
     pub spawn_terminal: Option<String>,
+    /// Minutes between automatic snapshots while the TUI runs (0 = off)
+    pub snapshot_interval_minutes: u64,
+    /// Number of automatic snapshots to keep
+    pub snapshot_keep: usize,
 }
 
 impl Default for ScreenSettings {
@@ -101,6 +154,8 @@ impl Default for ScreenSettings {
             default_shell: None,
             attach_mode: "exec".to_string(),
             spawn_terminal: Some("xterm".to_string()),
+            snapshot_interval_minutes: 0,
+            snapshot_keep: 10,
         }
     }
 }
@@ -115,6 +170,8 @@ pub struct IntegrationSettings {
     pub fzf_enabled: bool,
     /// Enable shell hooks
     pub shell_hooks: bool,
+    /// Command that receives copied text on stdin (None = OSC 52)
+    pub clipboard_command: Option<String>,
 }
 
 impl Default for IntegrationSettings {
@@ -123,6 +180,26 @@ impl Default for IntegrationSettings {
             git_status: true,
             fzf_enabled: true,
             shell_hooks: false,
+            clipboard_command: None,
+        }
+    }
+}
+
+/// Project discovery settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProjectSettings {
+    /// Directories to scan for git repositories and `.sesh` files
+    pub roots: Vec<String>,
+    /// How many directory levels below each root to search
+    pub max_depth: usize,
+}
+
+impl Default for ProjectSettings {
+    fn default() -> Self {
+        Self {
+            roots: Vec::new(),
+            max_depth: 2,
         }
     }
 }
@@ -198,6 +275,14 @@ impl Settings {
             .unwrap_or_else(|| PathBuf::from("~/.config/sesh/config.toml"))
     }
 
+    /// Get state directory path ($XDG_STATE_HOME/sesh)
+    pub fn state_dir() -> PathBuf {
+        dirs::state_dir()
+            .or_else(dirs::data_local_dir)
+            .map(|d| d.join("sesh"))
+            .unwrap_or_else(|| PathBuf::from("~/.local/state/sesh"))
+    }
+
     /// Get templates directory path
     pub fn templates_dir(&self) -> PathBuf {
         dirs::config_dir()
@@ -205,6 +290,13 @@ impl Settings {
             .unwrap_or_else(|| PathBuf::from("~/.config/sesh/templates"))
     }
 
+    /// Get themes directory path
+    pub fn themes_dir(&self) -> PathBuf {
+        dirs::config_dir()
+            .map(|d| d.join("sesh").join("themes"))
+            .unwrap_or_else(|| PathBuf::from("~/.config/sesh/themes"))
+    }
+
     /// Save settings to file
     pub fn save(&self, path: Option<&str>) -> Result<()> {
         let path = if let Some(p) = path {
@@ -252,6 +344,8 @@ mod tests {
         assert!(ui.show_preview);
         assert_eq!(ui.preview_lines, 8);
         assert_eq!(ui.refresh_interval_ms, 1000);
+        assert_eq!(ui.sort_by, SortKey::Frecency);
+        assert_eq!(ui.group_by, GroupBy::Host);
     }
 
     #[test]
@@ -279,6 +373,13 @@ mod tests {
         assert!(!integrations.shell_hooks);
     }
 
+    #[test]
+    fn test_default_project_settings() {
+        let projects = ProjectSettings::default();
+        assert!(projects.roots.is_empty());
+        assert_eq!(projects.max_depth, 2);
+    }
+
     #[test]
     fn test_default_keybindings() {
         let keys = KeyBindings::default();
@@ -317,6 +418,8 @@ theme = "light"
 show_preview = false
 preview_lines = 12
 refresh_interval_ms = 500
+sort_by = "name"
+group_by = "repo"
 
 [navigation]
 vim_keys = false
@@ -336,11 +439,17 @@ shell_hooks = true
 
 [keybindings]
 quit = ["q", "Ctrl-q"]
+
+[projects]
+roots = ["~/src", "~/work"]
+max_depth = 3
 "#;
         let settings: Settings = toml::from_str(toml).unwrap();
         assert_eq!(settings.ui.theme, "light");
         assert!(!settings.ui.show_preview);
         assert_eq!(settings.ui.preview_lines, 12);
+        assert_eq!(settings.ui.sort_by, SortKey::Name);
+        assert_eq!(settings.ui.group_by, GroupBy::Repo);
         assert!(!settings.navigation.vim_keys);
         assert_eq!(settings.screen.attach_mode, "spawn");
         assert_eq!(
@@ -348,6 +457,8 @@ quit = ["q", "Ctrl-q"]
             Some("alacritty".to_string())
         );
         assert!(settings.integrations.shell_hooks);
+        assert_eq!(settings.projects.roots, vec!["~/src", "~/work"]);
+        assert_eq!(settings.projects.max_depth, 3);
     }
 
     #[test]
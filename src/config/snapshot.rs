@@ -0,0 +1,282 @@
+//! Session snapshots
+//!
+//! Records the layout of running local sessions (windows, titles, working
+//! directories and foreground commands) so it can be recreated after a
+//! reboot. Each session is stored as a [`Template`], so any entry can be
+//! used as a template and a template file can be restored like a snapshot.
+
+use anyhow::{Context, Result};
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use super::templates::{self, Template, TemplateWindow};
+use super::Settings;
+use crate::screen::bulk::BulkResult;
+use crate::screen::local::{self, WindowProcess};
+use crate::screen::{Session, Window};
+
+/// Prefix of auto-snapshot file names
+const AUTO_PREFIX: &str = "snapshot-";
+
+/// Saved layout of one or more sessions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Snapshot {
+    /// When the snapshot was taken (RFC 3339)
+    pub taken_at: String,
+
+    /// Sessions, in template form
+    pub sessions: Vec<Template>,
+}
+
+impl Snapshot {
+    /// Take a snapshot of local sessions
+    pub async fn capture(sessions: &[Session]) -> Self {
+        let now = Local::now();
+        let mut templates = Vec::new();
+
+        for session in sessions.iter().filter(|s| s.is_local() && !s.is_virtual()) {
+            let windows = local::list_windows(&session.id).await.unwrap_or_default();
+            let processes = local::window_processes(session.pid);
+            let root = local::session_working_dir(session.pid);
+
+            let mut template = session_template(&session.name, root, &windows, &processes);
+            template.description = Some(format!(
+                "Snapshot of '{}' taken {}",
+                session.name,
+                now.format("%Y-%m-%d %H:%M")
+            ));
+            templates.push(template);
+        }
+
+        Self {
+            taken_at: now.to_rfc3339(),
+            sessions: templates,
+        }
+    }
+
+    /// Load a snapshot, or a single template as a one-session snapshot
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .context(format!("Failed to read snapshot: {:?}", path))?;
+        Self::parse(&content).context(format!("Failed to parse snapshot: {:?}", path))
+    }
+
+    /// Parse snapshot YAML, accepting a plain template too
+    pub fn parse(content: &str) -> Result<Self> {
+        match serde_yaml::from_str::<Snapshot>(content) {
+            Ok(snapshot) => Ok(snapshot),
+            Err(snapshot_err) => match serde_yaml::from_str::<Template>(content) {
+                Ok(template) => Ok(Self {
+                    taken_at: String::new(),
+                    sessions: vec![template],
+                }),
+                Err(_) => Err(snapshot_err.into()),
+            },
+        }
+    }
+
+    /// Snapshot as YAML
+    pub fn to_yaml(&self) -> Result<String> {
+        serde_yaml::to_string(self).context("Failed to serialize snapshot")
+    }
+
+    /// Write the snapshot to a file
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, self.to_yaml()?)
+            .context(format!("Failed to write snapshot: {:?}", path))
+    }
+}
+
+/// Template recreating a session's windows
+///
+/// Window directories equal to the root are left out, and the others are
+/// made relative to it when they are below it.
+pub fn session_template(
+    name: &str,
+    root: Option<String>,
+    windows: &[Window],
+    processes: &[WindowProcess],
+) -> Template {
+    let windows = windows
+        .iter()
+        .map(|window| {
+            let process = processes.iter().find(|p| p.number == window.number);
+            TemplateWindow {
+                name: window.name.clone(),
+                command: process.and_then(|p| p.command.clone()),
+                dir: process
+                    .and_then(|p| p.working_dir.as_deref())
+                    .and_then(|dir| relative_dir(dir, root.as_deref())),
+                splits: Vec::new(),
+            }
+        })
+        .collect();
+
+    Template {
+        name: name.to_string(),
+        description: None,
+        root,
+        on_create: Vec::new(),
+        windows,
+        variables: HashMap::new(),
+        warnings: Vec::new(),
+    }
+}
+
+/// Window directory as stored in a template (None when it is the root)
+fn relative_dir(dir: &str, root: Option<&str>) -> Option<String> {
+    let Some(root) = root else {
+        return Some(dir.to_string());
+    };
+
+    match Path::new(dir).strip_prefix(root) {
+        Ok(rest) if rest.as_os_str().is_empty() => None,
+        Ok(rest) => Some(rest.to_string_lossy().into_owned()),
+        Err(_) => Some(dir.to_string()),
+    }
+}
+
+/// Recreate the sessions of a snapshot
+///
+/// Sessions whose name is already running are skipped. Returns a result
+/// per session.
+pub async fn restore(
+    config: &Settings,
+    snapshot: &Snapshot,
+    running: &[Session],
+) -> Vec<BulkResult> {
+    let mut results = Vec::new();
+
+    for template in &snapshot.sessions {
+        let result = if running.iter().any(|s| s.name == template.name) {
+            Err("already running".to_string())
+        } else {
+            templates::create_from_template(config, template, &template.name, &HashMap::new())
+                .await
+                .map(|()| format!("restored ({} windows)", template.windows.len()))
+                .map_err(|e| e.to_string())
+        };
+
+        results.push(BulkResult {
+            label: template.name.clone(),
+            result,
+        });
+    }
+
+    results
+}
+
+/// Directory auto-snapshots are kept in
+pub fn auto_dir() -> PathBuf {
+    Settings::state_dir().join("snapshots")
+}
+
+/// Auto-snapshots, oldest first
+pub fn auto_snapshots() -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(auto_dir()) else {
+        return Vec::new();
+    };
+
+    let mut paths: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(AUTO_PREFIX) && name.ends_with(".yaml"))
+        })
+        .collect();
+
+    // Names embed the time, so they sort chronologically
+    paths.sort();
+    paths
+}
+
+/// Save an auto-snapshot, keeping only the newest `keep`
+pub fn save_auto(snapshot: &Snapshot, keep: usize) -> Result<PathBuf> {
+    let name = format!(
+        "{}{}.yaml",
+        AUTO_PREFIX,
+        Local::now().format("%Y%m%d-%H%M%S")
+    );
+    let path = auto_dir().join(name);
+    snapshot.save(&path)?;
+
+    let snapshots = auto_snapshots();
+    let excess = snapshots.len().saturating_sub(keep.max(1));
+    for old in &snapshots[..excess] {
+        let _ = std::fs::remove_file(old);
+    }
+
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_template() {
+        let windows = vec![
+            Window::new(0, "editor".to_string()),
+            Window::new(2, "logs".to_string()),
+            Window::new(3, "shell".to_string()),
+        ];
+        let processes = vec![
+            WindowProcess {
+                number: 0,
+                working_dir: Some("/srv/api".to_string()),
+                command: Some("nvim .".to_string()),
+            },
+            WindowProcess {
+                number: 2,
+                working_dir: Some("/srv/api/logs".to_string()),
+                command: Some("tail -f app.log".to_string()),
+            },
+            WindowProcess {
+                number: 3,
+                working_dir: Some("/tmp".to_string()),
+                command: None,
+            },
+        ];
+
+        let template = session_template("api", Some("/srv/api".to_string()), &windows, &processes);
+        let dirs: Vec<Option<&str>> = template.windows.iter().map(|w| w.dir.as_deref()).collect();
+        assert_eq!(dirs, vec![None, Some("logs"), Some("/tmp")]);
+        assert_eq!(
+            template.windows[1].command.as_deref(),
+            Some("tail -f app.log")
+        );
+        assert_eq!(template.windows[2].command, None);
+    }
+
+    #[test]
+    fn test_snapshot_roundtrip_and_template_fallback() {
+        let snapshot = Snapshot {
+            taken_at: "2024-05-01T12:00:00+00:00".to_string(),
+            sessions: vec![session_template(
+                "api",
+                Some("/srv/api".to_string()),
+                &[Window::new(0, "shell".to_string())],
+                &[],
+            )],
+        };
+
+        let parsed = Snapshot::parse(&snapshot.to_yaml().unwrap()).unwrap();
+        assert_eq!(parsed.sessions.len(), 1);
+        assert_eq!(parsed.sessions[0].name, "api");
+        assert_eq!(parsed.sessions[0].root.as_deref(), Some("/srv/api"));
+
+        let template = "name: web\nwindows:\n  - name: server\n    command: npm start\n";
+        let parsed = Snapshot::parse(template).unwrap();
+        assert_eq!(parsed.sessions[0].name, "web");
+        assert_eq!(parsed.sessions[0].windows.len(), 1);
+
+        assert!(Snapshot::parse("sessions: 3").is_err());
+    }
+}
@@ -5,10 +5,14 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Component, Path, PathBuf};
 
 use super::Settings;
-use crate::screen;
+use super::{snapshot, tmux};
+use crate::screen::{self, Session};
+
+/// Variable a saved template's project directory becomes
+pub const PROJECT_VARIABLE: &str = "PROJECT";
 
 /// Session template
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -33,6 +37,10 @@ pub struct Template {
     /// Variables for substitution
     #[serde(default)]
     pub variables: HashMap<String, TemplateVariable>,
+
+    /// Parts of a tmuxinator or tmuxp project that were ignored (not saved)
+    #[serde(skip)]
+    pub warnings: Vec<String>,
 }
 
 /// Window definition in a template
@@ -77,25 +85,31 @@ pub struct TemplateVariable {
 
 /// List all available templates
 pub fn list_templates(config: &Settings) -> Result<Vec<Template>> {
-    let templates_dir = config.templates_dir();
-
-    if !templates_dir.exists() {
-        return Ok(Vec::new());
-    }
-
     let mut templates = Vec::new();
 
-    for entry in std::fs::read_dir(&templates_dir)? {
-        let entry = entry?;
-        let path = entry.path();
+    if let Ok(entries) = std::fs::read_dir(config.templates_dir()) {
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if is_yaml(&path) {
+                if let Ok(template) = load_template_from_path(&path) {
+                    templates.push(template);
+                }
+            }
+        }
+    }
 
-        if path
-            .extension()
-            .map(|e| e == "yaml" || e == "yml")
-            .unwrap_or(false)
-        {
-            if let Ok(template) = load_template_from_path(&path) {
-                templates.push(template);
+    // tmuxinator and tmuxp projects, unless a template has the same name
+    for dir in tmux::project_dirs() {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            continue;
+        };
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if !is_yaml(&path) {
+                continue;
+            }
+            if let Ok(imported) = tmux::import_file(&path) {
+                if !templates.iter().any(|t| t.name == imported.template.name) {
+                    templates.push(imported.template);
+                }
             }
         }
     }
@@ -122,18 +136,41 @@ pub fn load_template(config: &Settings, name: &str) -> Result<Template> {
         return load_template_from_path(&yml_path);
     }
 
-    anyhow::bail!("Template '{}' not found", name)
+    // Templates named differently from their file, and tmux projects
+    list_templates(config)?
+        .into_iter()
+        .find(|t| t.name == name)
+        .ok_or_else(|| anyhow::anyhow!("Template '{}' not found", name))
+}
+
+/// Check whether a path has a YAML extension
+fn is_yaml(path: &Path) -> bool {
+    path.extension()
+        .map(|e| e == "yaml" || e == "yml")
+        .unwrap_or(false)
 }
 
 /// Load a template from a specific path
+///
+/// tmuxinator and tmuxp projects are converted, with what was ignored in
+/// the template's `warnings`.
 fn load_template_from_path(path: &PathBuf) -> Result<Template> {
     let content =
         std::fs::read_to_string(path).context(format!("Failed to read template: {:?}", path))?;
 
-    let template: Template =
-        serde_yaml::from_str(&content).context(format!("Failed to parse template: {:?}", path))?;
-
-    Ok(template)
+    match serde_yaml::from_str::<Template>(&content) {
+        Ok(template) => Ok(template),
+        Err(err) => {
+            let stem = path
+                .file_stem()
+                .map(|s| s.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            tmux::import_str(&content, &stem)
+                .map(|imported| imported.template)
+                .map_err(|_| err)
+                .context(format!("Failed to parse template: {:?}", path))
+        }
+    }
 }
 
 /// Create a session from a template
@@ -143,6 +180,17 @@ pub async fn create_from_template(
     session_name: &str,
     variables: &HashMap<String, String>,
 ) -> Result<()> {
+    // Variables that weren't given take their defaults
+    let mut variables = variables.clone();
+    for (key, variable) in &template.variables {
+        if let Some(ref default) = variable.default {
+            variables
+                .entry(key.clone())
+                .or_insert_with(|| default.clone());
+        }
+    }
+    let variables = &variables;
+
     // Expand variables in root directory
     let root = template
         .root
@@ -170,11 +218,8 @@ pub async fn create_from_template(
             screen::local::create_window(session_name, Some(&window.name)).await?;
         }
 
-        // Run command if specified
-        if let Some(ref cmd) = window.command {
-            let expanded_cmd = expand_variables(cmd, variables);
-            let cmd_with_newline = format!("{}\n", expanded_cmd);
-            screen::local::send_keys(session_name, &cmd_with_newline).await?;
+        for keys in window_keys(window, root.as_deref(), variables) {
+            screen::local::send_keys(session_name, None, &keys).await?;
         }
 
         // Small delay between windows
@@ -183,13 +228,163 @@ pub async fn create_from_template(
 
     // Run on_create commands
     for cmd in &template.on_create {
-        let expanded_cmd = expand_variables(cmd, variables);
-        screen::local::send_keys(session_name, &format!("{}\n", expanded_cmd)).await?;
+        let keys = screen::send::keys_for(&expand_variables(cmd, variables), true);
+        screen::local::send_keys(session_name, None, &keys).await?;
     }
 
     Ok(())
 }
 
+/// Keys to stuff into a new window: `cd` to its directory, then its command
+///
+/// Lines are escaped for `stuff`, so commands captured by a snapshot
+/// (`grep '^error' app.log`) are typed as they were run.
+fn window_keys(
+    window: &TemplateWindow,
+    root: Option<&str>,
+    variables: &HashMap<String, String>,
+) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    // Change to the window's directory (relative to root or absolute)
+    if let Some(ref dir) = window.dir {
+        let dir = PathBuf::from(expand_variables(dir, variables));
+        let dir = match root {
+            Some(root) if dir.is_relative() => PathBuf::from(root).join(dir),
+            _ => dir,
+        };
+        lines.push(format!(
+            "cd {}",
+            screen::remote::shell_quote(&dir.to_string_lossy())
+        ));
+    }
+
+    // Run command if specified
+    if let Some(ref cmd) = window.command {
+        lines.push(expand_variables(cmd, variables));
+    }
+
+    lines
+        .iter()
+        .map(|line| screen::send::keys_for(line, true))
+        .collect()
+}
+
+/// Build a template from a running local session
+///
+/// Windows, titles, working directories and foreground commands are read
+/// from the live session. A root under a project root or the home directory
+/// becomes `<prefix>/${PROJECT}` with the directory name as the default.
+pub async fn template_from_session(
+    config: &Settings,
+    session: &Session,
+    name: &str,
+) -> Result<Template> {
+    let windows = screen::local::list_windows(&session.id).await?;
+    let processes = screen::local::window_processes(session.pid);
+    let root = screen::local::session_working_dir(session.pid);
+
+    let mut template = snapshot::session_template(name, root.clone(), &windows, &processes);
+    template.description = Some(format!("Saved from session '{}'", session.name));
+
+    let mut prefixes: Vec<PathBuf> = config
+        .projects
+        .roots
+        .iter()
+        .map(|r| PathBuf::from(shellexpand::tilde(r).as_ref()))
+        .collect();
+    prefixes.extend(dirs::home_dir());
+
+    if let Some(root) = root {
+        if let Some((templated, default)) =
+            templatize_root(&root, &prefixes, dirs::home_dir().as_deref())
+        {
+            // Commands that mention the root follow it too
+            for window in &mut template.windows {
+                if let Some(ref mut command) = window.command {
+                    *command = command.replace(&root, &templated);
+                }
+            }
+            template.root = Some(templated);
+            template.variables.insert(
+                PROJECT_VARIABLE.to_string(),
+                TemplateVariable {
+                    prompt: Some("Project directory name:".to_string()),
+                    default: Some(default),
+                },
+            );
+        }
+    }
+
+    Ok(template)
+}
+
+/// Turn a root below one of `prefixes` into `<prefix>/${PROJECT}[/rest]`
+///
+/// Uses the longest matching prefix, written with `~` when under `home`.
+/// Returns the new root and the directory name `${PROJECT}` replaced.
+pub fn templatize_root(
+    root: &str,
+    prefixes: &[PathBuf],
+    home: Option<&Path>,
+) -> Option<(String, String)> {
+    let root = Path::new(root);
+    let (prefix, rest) = prefixes
+        .iter()
+        .filter_map(|prefix| Some((prefix, root.strip_prefix(prefix).ok()?)))
+        .filter(|(_, rest)| !rest.as_os_str().is_empty())
+        .max_by_key(|(prefix, _)| prefix.components().count())?;
+
+    let mut components = rest.components();
+    let project = match components.next()? {
+        Component::Normal(name) => name.to_string_lossy().into_owned(),
+        _ => return None,
+    };
+
+    let prefix = match home.and_then(|home| prefix.strip_prefix(home).ok()) {
+        Some(under_home) if under_home.as_os_str().is_empty() => "~".to_string(),
+        Some(under_home) => format!("~/{}", under_home.to_string_lossy()),
+        None => prefix.to_string_lossy().into_owned(),
+    };
+
+    let mut templated = format!("{}/${{{}}}", prefix.trim_end_matches('/'), PROJECT_VARIABLE);
+    let rest = components.as_path();
+    if !rest.as_os_str().is_empty() {
+        templated = format!("{}/{}", templated, rest.to_string_lossy());
+    }
+
+    Some((templated, project))
+}
+
+/// Write a template to `<templates_dir>/<name>.yaml`
+///
+/// Fails if the file exists, unless `force` is set.
+pub fn save_template(config: &Settings, template: &Template, force: bool) -> Result<PathBuf> {
+    let dir = config.templates_dir();
+    let path = dir.join(format!("{}.yaml", template.name));
+    if path.exists() && !force {
+        anyhow::bail!("Template '{}' already exists: {:?}", template.name, path);
+    }
+
+    std::fs::create_dir_all(&dir)?;
+    let yaml = serde_yaml::to_string(template).context("Failed to serialize template")?;
+    std::fs::write(&path, yaml).context(format!("Failed to write template: {:?}", path))?;
+
+    Ok(path)
+}
+
+/// Save a running local session as a template named `name`
+pub async fn save_from_session(
+    config: &Settings,
+    session: &Session,
+    name: &str,
+    force: bool,
+) -> Result<(Template, PathBuf)> {
+    let template = template_from_session(config, session, name).await?;
+    let path = save_template(config, &template, force)?;
+    Ok((template, path))
+}
+
 /// Expand variables in a string
 fn expand_variables(s: &str, variables: &HashMap<String, String>) -> String {
     let mut result = s.to_string();
@@ -241,6 +436,7 @@ pub fn generate_example_template() -> String {
             );
             vars
         },
+        warnings: Vec::new(),
     };
 
     serde_yaml::to_string(&template).unwrap_or_default()
@@ -259,6 +455,27 @@ mod tests {
         assert!(result.contains("myproject"));
     }
 
+    #[test]
+    fn test_templatize_root() {
+        let home = PathBuf::from("/home/dev");
+        let prefixes = vec![home.join("projects"), home.clone(), PathBuf::from("/srv")];
+
+        assert_eq!(
+            templatize_root("/home/dev/projects/api", &prefixes, Some(&home)),
+            Some(("~/projects/${PROJECT}".to_string(), "api".to_string()))
+        );
+        assert_eq!(
+            templatize_root("/home/dev/scratch/tool", &prefixes, Some(&home)),
+            Some(("~/${PROJECT}/tool".to_string(), "scratch".to_string()))
+        );
+        assert_eq!(
+            templatize_root("/srv/web", &prefixes, Some(&home)),
+            Some(("/srv/${PROJECT}".to_string(), "web".to_string()))
+        );
+        assert_eq!(templatize_root("/home/dev", &prefixes, Some(&home)), None);
+        assert_eq!(templatize_root("/opt/x", &prefixes, Some(&home)), None);
+    }
+
     #[test]
     fn test_parse_template() {
         let yaml = r#"
@@ -276,4 +493,58 @@ windows:
         assert_eq!(t.name, "test");
         assert_eq!(t.windows.len(), 2);
     }
+
+    #[test]
+    fn test_restored_window_keys_are_escaped() {
+        let captured = TemplateWindow {
+            name: "logs".to_string(),
+            command: Some(r"grep '^error' app.log | sed 's/\t/ /'".to_string()),
+            dir: Some("logs".to_string()),
+            splits: Vec::new(),
+        };
+        let snapshot = snapshot::Snapshot {
+            taken_at: String::new(),
+            sessions: vec![Template {
+                name: "api".to_string(),
+                description: None,
+                root: Some("/srv/api".to_string()),
+                on_create: Vec::new(),
+                windows: vec![captured],
+                variables: HashMap::new(),
+                warnings: Vec::new(),
+            }],
+        };
+
+        let restored = snapshot::Snapshot::parse(&snapshot.to_yaml().unwrap()).unwrap();
+        let keys = window_keys(
+            &restored.sessions[0].windows[0],
+            Some("/srv/api"),
+            &HashMap::new(),
+        );
+        assert_eq!(
+            keys,
+            vec![
+                "cd '/srv/api/logs'\r".to_string(),
+                r"grep '\^error' app.log | sed 's/\\t/ /'".to_string() + "\r",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_loaded_tmux_project_keeps_warnings() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("blog.yml");
+        std::fs::write(
+            &path,
+            "name: blog\nstartup_window: editor\nwindows:\n  - editor: vim\n",
+        )
+        .unwrap();
+
+        let template = load_template_from_path(&path).unwrap();
+        assert_eq!(template.name, "blog");
+        assert_eq!(
+            template.warnings,
+            vec!["unsupported key 'startup_window' ignored"]
+        );
+    }
 }
@@ -0,0 +1,471 @@
+//! tmuxinator and tmuxp projects
+//!
+//! Converts tmux project files into [`Template`]s so they can be imported
+//! or used directly. Windows and panes map onto template windows and
+//! splits; keys with no screen equivalent are reported as warnings.
+
+use anyhow::{bail, Context, Result};
+use serde_yaml::{Mapping, Value};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use super::templates::{Template, TemplateSplit, TemplateWindow};
+
+/// Project file format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// tmuxinator (`name`, `root`, windows as `- name: command`)
+    Tmuxinator,
+    /// tmuxp (`session_name`, `start_directory`, `window_name`)
+    Tmuxp,
+}
+
+impl Format {
+    /// Display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Format::Tmuxinator => "tmuxinator",
+            Format::Tmuxp => "tmuxp",
+        }
+    }
+}
+
+/// A converted project
+///
+/// Parts of the project that were ignored are in the template's `warnings`.
+#[derive(Debug, Clone)]
+pub struct Imported {
+    /// The project as a template
+    pub template: Template,
+    /// Format it was read from
+    pub format: Format,
+}
+
+/// Top-level keys that are converted
+const TMUXINATOR_KEYS: &[&str] = &[
+    "name",
+    "project_name",
+    "root",
+    "project_root",
+    "windows",
+    "tabs",
+    "pre_window",
+    "on_project_start",
+];
+const TMUXP_KEYS: &[&str] = &[
+    "session_name",
+    "start_directory",
+    "windows",
+    "shell_command_before",
+];
+
+/// Window keys that are converted
+const TMUXINATOR_WINDOW_KEYS: &[&str] = &["root", "panes", "layout"];
+const TMUXP_WINDOW_KEYS: &[&str] = &[
+    "window_name",
+    "start_directory",
+    "panes",
+    "layout",
+    "shell_command_before",
+];
+
+/// Directories tmuxinator and tmuxp keep projects in
+pub fn project_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    if let Some(config) = dirs::config_dir() {
+        dirs.push(config.join("tmuxinator"));
+        dirs.push(config.join("tmuxp"));
+    }
+    if let Some(home) = dirs::home_dir() {
+        dirs.push(home.join(".tmuxinator"));
+        dirs.push(home.join(".tmuxp"));
+    }
+    dirs
+}
+
+/// Work out which tool a project file is for
+pub fn detect(value: &Value) -> Option<Format> {
+    let map = value.as_mapping()?;
+    if map.contains_key("session_name") {
+        return Some(Format::Tmuxp);
+    }
+
+    // tmuxinator windows are single-key maps (`- editor: vim`)
+    let windows = map
+        .get("windows")
+        .or_else(|| map.get("tabs"))?
+        .as_sequence()?;
+    let tmuxinator_window = |w: &Value| {
+        w.as_mapping()
+            .is_some_and(|m| m.len() == 1 && !m.contains_key("name"))
+    };
+    windows
+        .iter()
+        .all(tmuxinator_window)
+        .then_some(Format::Tmuxinator)
+}
+
+/// Convert a project file
+pub fn import_file(path: &Path) -> Result<Imported> {
+    let content = std::fs::read_to_string(path)
+        .context(format!("Failed to read project file: {:?}", path))?;
+    let fallback = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    import_str(&content, &fallback).context(format!("Failed to import {:?}", path))
+}
+
+/// Convert project YAML, naming it `fallback_name` if it has no name
+pub fn import_str(content: &str, fallback_name: &str) -> Result<Imported> {
+    let value: Value = serde_yaml::from_str(content).context("Invalid YAML")?;
+    let Some(format) = detect(&value) else {
+        bail!("Not a tmuxinator or tmuxp project");
+    };
+    let map = value.as_mapping().expect("detected projects are mappings");
+
+    let mut warnings = Vec::new();
+    if content.contains("<%") {
+        warnings.push("ERB tags (<% %>) are not evaluated".to_string());
+    }
+
+    let mut template = match format {
+        Format::Tmuxinator => tmuxinator(map, fallback_name, &mut warnings),
+        Format::Tmuxp => tmuxp(map, fallback_name, &mut warnings),
+    };
+    template.warnings = warnings;
+
+    Ok(Imported { template, format })
+}
+
+/// Convert a tmuxinator project
+fn tmuxinator(map: &Mapping, fallback_name: &str, warnings: &mut Vec<String>) -> Template {
+    unsupported_keys(map, TMUXINATOR_KEYS, "", warnings);
+
+    let pre_window = commands(map.get("pre_window"));
+    let windows = map
+        .get("windows")
+        .or_else(|| map.get("tabs"))
+        .and_then(Value::as_sequence)
+        .map(|windows| windows.as_slice())
+        .unwrap_or_default()
+        .iter()
+        .filter_map(Value::as_mapping)
+        .filter_map(|window| window.iter().next())
+        .map(|(name, body)| {
+            let name = scalar(name).unwrap_or_default();
+            let context = format!("window '{}': ", name);
+
+            let (dir, layout, panes) = match body.as_mapping() {
+                Some(window) => {
+                    unsupported_keys(window, TMUXINATOR_WINDOW_KEYS, &context, warnings);
+                    (
+                        window.get("root").and_then(scalar),
+                        window.get("layout").and_then(scalar),
+                        window
+                            .get("panes")
+                            .map(|panes| pane_commands(panes, &context, warnings))
+                            .unwrap_or_default(),
+                    )
+                }
+                None => (None, None, vec![commands(Some(body))]),
+            };
+
+            let window = build_window(name, dir, layout.as_deref(), panes, &pre_window);
+            split_warning(&window, &context, warnings);
+            window
+        })
+        .collect();
+
+    Template {
+        name: map
+            .get("name")
+            .or_else(|| map.get("project_name"))
+            .and_then(scalar)
+            .unwrap_or_else(|| fallback_name.to_string()),
+        description: Some("Imported from tmuxinator".to_string()),
+        root: map
+            .get("root")
+            .or_else(|| map.get("project_root"))
+            .and_then(scalar),
+        on_create: commands(map.get("on_project_start")),
+        windows,
+        variables: HashMap::new(),
+        warnings: Vec::new(),
+    }
+}
+
+/// Convert a tmuxp workspace
+fn tmuxp(map: &Mapping, fallback_name: &str, warnings: &mut Vec<String>) -> Template {
+    unsupported_keys(map, TMUXP_KEYS, "", warnings);
+
+    let before = commands(map.get("shell_command_before"));
+    let windows = map
+        .get("windows")
+        .and_then(Value::as_sequence)
+        .map(|windows| windows.as_slice())
+        .unwrap_or_default()
+        .iter()
+        .filter_map(Value::as_mapping)
+        .enumerate()
+        .map(|(index, window)| {
+            let name = window
+                .get("window_name")
+                .and_then(scalar)
+                .unwrap_or_else(|| index.to_string());
+            let context = format!("window '{}': ", name);
+            unsupported_keys(window, TMUXP_WINDOW_KEYS, &context, warnings);
+
+            let mut pre = before.clone();
+            pre.extend(commands(window.get("shell_command_before")));
+            let panes = window
+                .get("panes")
+                .map(|panes| pane_commands(panes, &context, warnings))
+                .unwrap_or_default();
+
+            let window = build_window(
+                name,
+                window.get("start_directory").and_then(scalar),
+                window.get("layout").and_then(scalar).as_deref(),
+                panes,
+                &pre,
+            );
+            split_warning(&window, &context, warnings);
+            window
+        })
+        .collect();
+
+    Template {
+        name: map
+            .get("session_name")
+            .and_then(scalar)
+            .unwrap_or_else(|| fallback_name.to_string()),
+        description: Some("Imported from tmuxp".to_string()),
+        root: map.get("start_directory").and_then(scalar),
+        on_create: Vec::new(),
+        windows,
+        variables: HashMap::new(),
+        warnings: Vec::new(),
+    }
+}
+
+/// Build a window whose first pane is its command and the rest splits
+///
+/// Commands that run before every window are prepended to each pane.
+fn build_window(
+    name: String,
+    dir: Option<String>,
+    layout: Option<&str>,
+    panes: Vec<Vec<String>>,
+    pre: &[String],
+) -> TemplateWindow {
+    let command_line = |commands: &[String]| {
+        let all: Vec<&str> = pre.iter().chain(commands).map(String::as_str).collect();
+        (!all.is_empty()).then(|| all.join("; "))
+    };
+
+    let mut panes = panes.into_iter();
+    let command = command_line(&panes.next().unwrap_or_default());
+    let splits = panes
+        .map(|pane| TemplateSplit {
+            command: command_line(&pane),
+            size: None,
+            direction: layout.and_then(split_direction).map(String::from),
+        })
+        .collect();
+
+    TemplateWindow {
+        name,
+        command,
+        dir,
+        splits,
+    }
+}
+
+/// Warn that a window's extra panes are kept as splits but not created
+///
+/// Splits are regions of an attached display, so sessions started from a
+/// template only get each window's first pane.
+fn split_warning(window: &TemplateWindow, context: &str, warnings: &mut Vec<String>) {
+    if !window.splits.is_empty() {
+        warnings.push(format!("{}panes after the first are not created", context));
+    }
+}
+
+/// Split direction for a tmux layout
+///
+/// tmux names layouts after how panes are arranged (`even-horizontal`
+/// places them side by side), screen after the dividing line.
+fn split_direction(layout: &str) -> Option<&'static str> {
+    match layout {
+        "even-horizontal" | "main-vertical" => Some("vertical"),
+        "even-vertical" | "main-horizontal" => Some("horizontal"),
+        _ => None,
+    }
+}
+
+/// Commands of each pane
+///
+/// A pane is a command, a list of commands, `null`, a tmuxp map with
+/// `shell_command`, or a tmuxinator map from a pane title to commands.
+fn pane_commands(panes: &Value, context: &str, warnings: &mut Vec<String>) -> Vec<Vec<String>> {
+    let Some(panes) = panes.as_sequence() else {
+        return vec![commands(Some(panes))];
+    };
+
+    panes
+        .iter()
+        .map(|pane| match pane.as_mapping() {
+            Some(map) if map.contains_key("shell_command") => {
+                unsupported_keys(
+                    map,
+                    &["shell_command"],
+                    &format!("{}pane ", context),
+                    warnings,
+                );
+                commands(map.get("shell_command"))
+            }
+            Some(map) if map.len() == 1 => commands(map.values().next()),
+            Some(_) => {
+                warnings.push(format!("{}unrecognised pane ignored", context));
+                Vec::new()
+            }
+            None => commands(Some(pane)),
+        })
+        .collect()
+}
+
+/// A command or list of commands as a list
+fn commands(value: Option<&Value>) -> Vec<String> {
+    match value {
+        Some(Value::Sequence(items)) => items.iter().filter_map(scalar).collect(),
+        Some(value) => scalar(value).into_iter().collect(),
+        None => Vec::new(),
+    }
+}
+
+/// A string, number or boolean as text (None for null and collections)
+fn scalar(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+/// Warn about keys that aren't converted
+fn unsupported_keys(map: &Mapping, known: &[&str], context: &str, warnings: &mut Vec<String>) {
+    for key in map.keys().filter_map(Value::as_str) {
+        if !known.contains(&key) {
+            warnings.push(format!("{}unsupported key '{}' ignored", context, key));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_import_tmuxinator() {
+        let yaml = r#"
+name: blog
+root: ~/code/blog
+on_project_start: docker compose up -d
+pre_window: nvm use
+startup_window: editor
+windows:
+  - editor:
+      layout: main-vertical
+      panes:
+        - vim
+        - - cd test
+          - guard
+  - server: bundle exec rails s
+  - shell:
+"#;
+        let imported = import_str(yaml, "fallback").unwrap();
+        assert_eq!(imported.format, Format::Tmuxinator);
+
+        let template = &imported.template;
+        assert_eq!(template.name, "blog");
+        assert_eq!(template.root.as_deref(), Some("~/code/blog"));
+        assert_eq!(template.on_create, vec!["docker compose up -d"]);
+        assert_eq!(template.windows.len(), 3);
+
+        let editor = &template.windows[0];
+        assert_eq!(editor.command.as_deref(), Some("nvm use; vim"));
+        assert_eq!(editor.splits.len(), 1);
+        assert_eq!(
+            editor.splits[0].command.as_deref(),
+            Some("nvm use; cd test; guard")
+        );
+        assert_eq!(editor.splits[0].direction.as_deref(), Some("vertical"));
+        assert_eq!(
+            template.windows[1].command.as_deref(),
+            Some("nvm use; bundle exec rails s")
+        );
+        assert_eq!(template.windows[2].command.as_deref(), Some("nvm use"));
+
+        assert_eq!(
+            template.warnings,
+            vec![
+                "unsupported key 'startup_window' ignored",
+                "window 'editor': panes after the first are not created",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_import_tmuxp() {
+        let yaml = r#"
+session_name: api
+start_directory: ~/code/api
+shell_command_before: source .env
+before_script: ./bootstrap.sh
+windows:
+  - window_name: dev
+    start_directory: src
+    panes:
+      - shell_command:
+          - make watch
+        focus: true
+      - null
+  - panes:
+      - htop
+"#;
+        let imported = import_str(yaml, "fallback").unwrap();
+        assert_eq!(imported.format, Format::Tmuxp);
+
+        let template = &imported.template;
+        assert_eq!(template.name, "api");
+        assert_eq!(template.root.as_deref(), Some("~/code/api"));
+
+        let dev = &template.windows[0];
+        assert_eq!(dev.dir.as_deref(), Some("src"));
+        assert_eq!(dev.command.as_deref(), Some("source .env; make watch"));
+        assert_eq!(dev.splits[0].command.as_deref(), Some("source .env"));
+        assert_eq!(template.windows[1].name, "1");
+        assert_eq!(
+            template.windows[1].command.as_deref(),
+            Some("source .env; htop")
+        );
+
+        assert_eq!(
+            template.warnings,
+            vec![
+                "unsupported key 'before_script' ignored",
+                "window 'dev': pane unsupported key 'focus' ignored",
+                "window 'dev': panes after the first are not created",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_detect_rejects_native_templates() {
+        let native = "name: web\nwindows:\n  - name: editor\n    command: nvim\n";
+        assert!(import_str(native, "web").is_err());
+    }
+}
@@ -5,7 +5,7 @@
 use anyhow::Result;
 use crossterm::event::{
     self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyModifiers,
-    MouseEvent, MouseEventKind,
+    MouseEvent,
 };
 use crossterm::terminal::{
     disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
@@ -15,6 +15,8 @@ use std::io::{stdout, Stdout};
 use std::time::Duration;
 use tokio::sync::mpsc;
 
+use crate::screen::selection::{Motion, SelectionKind};
+
 /// Application events
 #[derive(Debug, Clone)]
 pub enum AppEvent {
@@ -75,6 +77,37 @@ pub enum Action {
     ToggleHelp,
     TogglePreview,
     SwitchHost,
+    FocusNext,
+    ToggleFollow,
+    ToggleZoom,
+    ToggleLineNumbers,
+    CyclePreviewWindow,
+    SearchNext,
+    SearchPrev,
+    ViewDashboard,
+    Grep,
+    SendText,
+    Console,
+    SaveTemplate,
+    EnterCopyMode,
+    MoveWindowUp,
+    MoveWindowDown,
+    MoveWindow,
+    CycleSort,
+    CycleGroup,
+    ToggleGroup,
+
+    // Marking
+    ToggleMark,
+    MarkRange,
+    MarkAll,
+    SendCommand,
+
+    // Copy mode
+    CopyMotion(Motion),
+    CopyVisual(SelectionKind),
+    Yank,
+    YankToBuffer,
 
     // Input mode
     InputChar(char),
@@ -82,6 +115,7 @@ pub enum Action {
     InputDelete,
     InputConfirm,
     InputCancel,
+    InputComplete,
 
     // System
     Quit,
@@ -211,6 +245,7 @@ fn normal_mode_action(key: KeyEvent) -> Action {
         KeyCode::Char('A') => Action::AttachSpawn,
         KeyCode::Char('w') => Action::ViewWindows,
         KeyCode::Char('t') => Action::ViewTemplates,
+        KeyCode::Char('T') => Action::SaveTemplate,
         KeyCode::Char('S') => Action::ViewSettings,
         KeyCode::Char('r') => Action::Refresh,
         KeyCode::Char('F') => Action::RefreshAll, // F for full refresh including remotes
@@ -221,7 +256,66 @@ fn normal_mode_action(key: KeyEvent) -> Action {
         // UI toggles
         KeyCode::Char('?') => Action::ToggleHelp,
         KeyCode::Char('p') => Action::TogglePreview,
-        KeyCode::Tab => Action::SwitchHost,
+        KeyCode::Tab => Action::FocusNext,
+        KeyCode::BackTab => Action::SwitchHost,
+        KeyCode::Char('f') => Action::ToggleFollow,
+        KeyCode::Char('Z') => Action::ToggleZoom,
+        KeyCode::Char('#') => Action::ToggleLineNumbers,
+        KeyCode::Char('W') => Action::CyclePreviewWindow,
+        KeyCode::Char('N') => Action::SearchPrev,
+        KeyCode::Char('D') => Action::ViewDashboard,
+        KeyCode::Char('v') => Action::EnterCopyMode,
+        KeyCode::Char('s') => Action::Grep,
+        KeyCode::Char('i') => Action::SendText,
+        KeyCode::Char(':') => Action::Console,
+        KeyCode::Char('<') => Action::MoveWindowUp,
+        KeyCode::Char('>') => Action::MoveWindowDown,
+        KeyCode::Char('m') => Action::MoveWindow,
+        KeyCode::Char('o') => Action::CycleSort,
+        KeyCode::Char('O') => Action::CycleGroup,
+        KeyCode::Char('z') => Action::ToggleGroup,
+
+        // Marking and bulk operations
+        KeyCode::Char(' ') => Action::ToggleMark,
+        KeyCode::Char('V') => Action::MarkRange,
+        KeyCode::Char('*') => Action::MarkAll,
+        KeyCode::Char('c') => Action::SendCommand,
+
+        _ => Action::None,
+    }
+}
+
+/// Actions in the preview copy mode
+pub fn copy_mode_action(key: KeyEvent) -> Action {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+
+    match key.code {
+        KeyCode::Char('c') if ctrl => Action::ForceQuit,
+        KeyCode::Char('u') if ctrl => Action::CopyMotion(Motion::HalfPageUp),
+        KeyCode::Char('d') if ctrl => Action::CopyMotion(Motion::HalfPageDown),
+        KeyCode::Char('v') if ctrl => Action::CopyVisual(SelectionKind::Block),
+
+        KeyCode::Char('h') | KeyCode::Left => Action::CopyMotion(Motion::Left),
+        KeyCode::Char('l') | KeyCode::Right => Action::CopyMotion(Motion::Right),
+        KeyCode::Char('k') | KeyCode::Up => Action::CopyMotion(Motion::Up),
+        KeyCode::Char('j') | KeyCode::Down => Action::CopyMotion(Motion::Down),
+        KeyCode::Char('0') | KeyCode::Home => Action::CopyMotion(Motion::LineStart),
+        KeyCode::Char('^') => Action::CopyMotion(Motion::FirstNonBlank),
+        KeyCode::Char('$') | KeyCode::End => Action::CopyMotion(Motion::LineEnd),
+        KeyCode::Char('w') => Action::CopyMotion(Motion::WordForward),
+        KeyCode::Char('b') => Action::CopyMotion(Motion::WordBackward),
+        KeyCode::Char('e') => Action::CopyMotion(Motion::WordEnd),
+        KeyCode::Char('g') => Action::CopyMotion(Motion::Top),
+        KeyCode::Char('G') => Action::CopyMotion(Motion::Bottom),
+        KeyCode::PageUp => Action::CopyMotion(Motion::HalfPageUp),
+        KeyCode::PageDown => Action::CopyMotion(Motion::HalfPageDown),
+
+        KeyCode::Char('v') => Action::CopyVisual(SelectionKind::Char),
+        KeyCode::Char('V') => Action::CopyVisual(SelectionKind::Line),
+        KeyCode::Char('y') | KeyCode::Enter => Action::Yank,
+        KeyCode::Char('Y') => Action::YankToBuffer,
+
+        KeyCode::Esc | KeyCode::Char('q') => Action::Back,
 
         _ => Action::None,
     }
@@ -249,39 +343,20 @@ fn input_mode_action(key: KeyEvent) -> Action {
     match key.code {
         KeyCode::Esc => Action::InputCancel,
         KeyCode::Enter => Action::InputConfirm,
+        KeyCode::Tab => Action::InputComplete,
         KeyCode::Backspace => Action::InputBackspace,
         KeyCode::Delete => Action::InputDelete,
         KeyCode::Char(c) => Action::InputChar(c),
         KeyCode::Left => Action::Left,
         KeyCode::Right => Action::Right,
+        KeyCode::Up => Action::Up,
+        KeyCode::Down => Action::Down,
         KeyCode::Home => Action::Top,
         KeyCode::End => Action::Bottom,
         _ => Action::None,
     }
 }
 
-/// Parse mouse event into an action
-pub fn mouse_to_action(
-    mouse: MouseEvent,
-    list_bounds: (u16, u16, u16, u16),
-) -> Option<(Action, usize)> {
-    let (x, y, width, height) = list_bounds;
-
-    // Check if mouse is within list bounds
-    if mouse.column < x || mouse.column >= x + width || mouse.row < y || mouse.row >= y + height {
-        return None;
-    }
-
-    let row_index = (mouse.row - y) as usize;
-
-    match mouse.kind {
-        MouseEventKind::Down(event::MouseButton::Left) => Some((Action::Select, row_index)),
-        MouseEventKind::ScrollUp => Some((Action::Up, row_index)),
-        MouseEventKind::ScrollDown => Some((Action::Down, row_index)),
-        _ => None,
-    }
-}
-
 /// Terminal management
 pub struct Terminal {
     /// The terminal backend
@@ -290,11 +365,15 @@ pub struct Terminal {
 
 impl Terminal {
     /// Create and initialize a new terminal
-    pub fn new() -> Result<Self> {
+    ///
+    /// Mouse capture is only enabled when `mouse` is set.
+    pub fn new(mouse: bool) -> Result<Self> {
         enable_raw_mode()?;
         let mut stdout = stdout();
         stdout.execute(EnterAlternateScreen)?;
-        stdout.execute(EnableMouseCapture)?;
+        if mouse {
+            stdout.execute(EnableMouseCapture)?;
+        }
 
         let backend = ratatui::backend::CrosstermBackend::new(stdout);
         let terminal = ratatui::Terminal::new(backend)?;
@@ -341,6 +420,9 @@ mod tests {
 
         let key = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
         assert_eq!(key_to_action(key, true, false), Action::InputCancel);
+
+        let key = KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE);
+        assert_eq!(key_to_action(key, true, false), Action::InputComplete);
     }
 
     #[test]
@@ -0,0 +1,137 @@
+//! Clipboard integration
+//!
+//! Copies text to the system clipboard, either with a configured command
+//! (`wl-copy`, `xclip -selection clipboard`, `pbcopy`, ...) or with the OSC 52
+//! terminal escape sequence, which also works over SSH.
+
+use anyhow::{bail, Context, Result};
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Largest chunk of an OSC 52 sequence GNU screen passes through in one DCS string
+const SCREEN_DCS_CHUNK: usize = 76;
+
+/// Copy text to the clipboard
+///
+/// Returns a short description of where the text went.
+pub fn copy(command: Option<&str>, text: &str) -> Result<&'static str> {
+    match command.filter(|c| !c.trim().is_empty()) {
+        Some(command) => {
+            copy_with_command(command, text)?;
+            Ok("clipboard")
+        }
+        None => {
+            let sequence = osc52_sequence(
+                text,
+                std::env::var_os("STY").is_some(),
+                std::env::var_os("TMUX").is_some(),
+            );
+            let mut stdout = std::io::stdout();
+            stdout
+                .write_all(sequence.as_bytes())
+                .and_then(|_| stdout.flush())
+                .context("Failed to write OSC 52 sequence")?;
+            Ok("terminal clipboard (OSC 52)")
+        }
+    }
+}
+
+/// Pipe text into a clipboard command run by the shell
+fn copy_with_command(command: &str, text: &str) -> Result<()> {
+    let mut child = Command::new("sh")
+        .args(["-c", command])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to run '{}'", command))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(text.as_bytes())?;
+    }
+
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("'{}' failed: {}", command, stderr.trim());
+    }
+
+    Ok(())
+}
+
+/// Build the OSC 52 sequence that sets the clipboard
+///
+/// Inside GNU screen or tmux the sequence is wrapped so the multiplexer
+/// passes it on to the outer terminal.
+pub fn osc52_sequence(text: &str, in_screen: bool, in_tmux: bool) -> String {
+    let sequence = format!("\x1b]52;c;{}\x07", base64(text.as_bytes()));
+
+    if in_screen {
+        // screen limits the length of DCS strings, so send several
+        sequence
+            .as_bytes()
+            .chunks(SCREEN_DCS_CHUNK)
+            .map(|chunk| format!("\x1bP{}\x1b\\", String::from_utf8_lossy(chunk)))
+            .collect()
+    } else if in_tmux {
+        format!("\x1bPtmux;{}\x1b\\", sequence.replace('\x1b', "\x1b\x1b"))
+    } else {
+        sequence
+    }
+}
+
+/// Standard base64 encoding with padding
+fn base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+
+        out.push(ALPHABET[(n >> 18) as usize & 63] as char);
+        out.push(ALPHABET[(n >> 12) as usize & 63] as char);
+        out.push(if chunk.len() > 1 {
+            ALPHABET[(n >> 6) as usize & 63] as char
+        } else {
+            '='
+        });
+        out.push(if chunk.len() > 2 {
+            ALPHABET[n as usize & 63] as char
+        } else {
+            '='
+        });
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base64() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"error: x"), "ZXJyb3I6IHg=");
+    }
+
+    #[test]
+    fn test_osc52_sequence() {
+        assert_eq!(osc52_sequence("foo", false, false), "\x1b]52;c;Zm9v\x07");
+        assert_eq!(
+            osc52_sequence("foo", false, true),
+            "\x1bPtmux;\x1b\x1b]52;c;Zm9v\x07\x1b\\"
+        );
+
+        let wrapped = osc52_sequence(&"x".repeat(100), true, false);
+        assert!(wrapped.starts_with("\x1bP\x1b]52;c;"));
+        assert_eq!(wrapped.matches("\x1bP").count(), 2);
+    }
+}
@@ -1,7 +1,8 @@
 //! Integrations module
 //!
-//! Provides integration with external tools like git and fzf.
+//! Provides integration with external tools like git, fzf and the clipboard.
 
+pub mod clipboard;
 pub mod fzf;
 pub mod git;
 pub mod shell;
@@ -45,7 +45,7 @@ _sesh_completions() {
 
     case "$prev" in
         sesh)
-            COMPREPLY=($(compgen -W "list new attach detach kill start templates windows" -- "$cur"))
+            COMPREPLY=($(compgen -W "list new attach detach kill start templates windows here" -- "$cur"))
             ;;
         attach|detach|kill|windows)
             local sessions=$(screen -ls 2>/dev/null | grep -oP '\d+\.\K[^\s]+' 2>/dev/null)
@@ -105,6 +105,7 @@ _sesh() {
         'start:Create session from template'
         'templates:List available templates'
         'windows:Show windows in a session'
+        'here:Create/attach to the current project session'
     )
 
     case "$words[2]" in
@@ -206,18 +207,10 @@ function sesh-new --description "Create a new screen session"
     end
 end
 
-# Project session: creates or attaches to session named after current dir
+# Project session: creates or attaches to the session for the current project
+# Reads .sesh from the current directory or its git root
 function sesh-here --description "Create/attach to session for current directory"
-    set -l session_name (basename $PWD)
-
-    if screen -ls 2>/dev/null | string match -qr "\.$session_name\s"
-        echo "Attaching to existing session: $session_name"
-        sesh attach $session_name
-    else
-        echo "Creating new session: $session_name"
-        sesh new $session_name
-        sesh attach $session_name
-    end
+    command sesh here $argv
 end
 
 # ============================================================
@@ -255,6 +248,7 @@ complete -c sesh -n __fish_sesh_needs_command -a kill -d "Kill a session"
 complete -c sesh -n __fish_sesh_needs_command -a start -d "Create session from template"
 complete -c sesh -n __fish_sesh_needs_command -a templates -d "List available templates"
 complete -c sesh -n __fish_sesh_needs_command -a windows -d "Show windows in a session"
+complete -c sesh -n __fish_sesh_needs_command -a here -d "Create/attach to the current project session"
 
 # Session name completions
 complete -c sesh -n "__fish_sesh_using_command attach" -a "(__fish_sesh_sessions)" -d "Session"
@@ -10,7 +10,7 @@ mod integrations;
 mod screen;
 mod ui;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
@@ -24,7 +24,7 @@ pub struct Cli {
     pub config: Option<String>,
 
     /// Connect to a remote host
-    #[arg(short = 'H', long, value_name = "HOST")]
+    #[arg(short = 'H', long, value_name = "HOST", global = true)]
     pub host: Option<String>,
 
     /// Enable debug logging
@@ -42,6 +42,10 @@ pub enum Commands {
         /// Show sessions from all configured hosts
         #[arg(short, long)]
         all: bool,
+
+        /// Filter query (e.g. "host:prod status:detached age:>2d api")
+        #[arg(short, long)]
+        filter: Option<String>,
     },
 
     /// Create a new screen session
@@ -64,6 +68,13 @@ pub enum Commands {
         spawn: bool,
     },
 
+    /// Attach to the previously used session
+    Last {
+        /// Open in a new terminal window instead of replacing current process
+        #[arg(short, long)]
+        spawn: bool,
+    },
+
     /// Detach a session (remote detach)
     Detach {
         /// Session name or ID
@@ -97,12 +108,209 @@ pub enum Commands {
     /// List available templates
     Templates,
 
+    /// Manage templates
+    Template {
+        #[command(subcommand)]
+        command: TemplateCommand,
+    },
+
     /// Show windows in a session
     Windows {
         /// Session name or ID
         session: String,
     },
 
+    /// Create, rename, kill or move windows in a session
+    Window {
+        #[command(subcommand)]
+        command: WindowCommand,
+    },
+
+    /// Create or attach to the session for the current project
+    Here {
+        /// Override session name
+        #[arg(short, long)]
+        name: Option<String>,
+
+        /// Open in a new terminal window instead of replacing current process
+        #[arg(short, long)]
+        spawn: bool,
+
+        /// Create the session without attaching to it
+        #[arg(short, long)]
+        detached: bool,
+    },
+
+    /// Search the scrollback of every window of every session
+    Grep {
+        /// Regular expression (case-insensitive unless it has capitals)
+        pattern: String,
+
+        /// Lines of context to show around each match
+        #[arg(short = 'C', long, default_value_t = 0, value_name = "N")]
+        context: usize,
+
+        /// Search sessions on all configured hosts too
+        #[arg(short, long)]
+        all: bool,
+    },
+
+    /// Print or save the scrollback of a session's windows
+    Capture {
+        /// Session name or ID
+        session: String,
+
+        /// Window number (default: window 0)
+        #[arg(short, long, conflicts_with = "all_windows")]
+        window: Option<usize>,
+
+        /// Capture every window, each under its own header
+        #[arg(short = 'A', long)]
+        all_windows: bool,
+
+        /// Keep only the last N lines
+        #[arg(short = 'n', long, value_name = "N")]
+        lines: Option<usize>,
+
+        /// Drop blank lines at the end
+        #[arg(long)]
+        strip_trailing: bool,
+
+        /// Start with a header naming the session, window, host and time
+        #[arg(long)]
+        header: bool,
+
+        /// Write to a file instead of stdout ("-" for stdout)
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<String>,
+    },
+
+    /// Follow a window's output read-only, without attaching (q to quit)
+    Watch {
+        /// Session name or ID
+        session: String,
+
+        /// Window number (default: window 0)
+        #[arg(short, long)]
+        window: Option<usize>,
+
+        /// Refresh interval in milliseconds
+        #[arg(short, long, default_value_t = 1000, value_name = "MS")]
+        interval: u64,
+    },
+
+    /// Type text into a session's windows, followed by Enter
+    Send {
+        /// Session name or ID
+        session: String,
+
+        /// Window number (default: the session's current window)
+        #[arg(short, long, conflicts_with = "all_windows")]
+        window: Option<usize>,
+
+        /// Send to every window of the session
+        #[arg(short = 'A', long)]
+        all_windows: bool,
+
+        /// Don't press Enter after the text
+        #[arg(long)]
+        no_enter: bool,
+
+        /// Text to type (after `--`)
+        #[arg(last = true, required = true)]
+        text: Vec<String>,
+    },
+
+    /// Save the layout of local sessions to a YAML file
+    ///
+    /// Records windows, titles, working directories and foreground
+    /// commands; each session is stored as a template.
+    Snapshot {
+        /// Sessions to save
+        sessions: Vec<String>,
+
+        /// Save every local session
+        #[arg(short, long, conflicts_with = "sessions")]
+        all: bool,
+
+        /// Output file ("-" for stdout, the default)
+        #[arg(short, long, conflicts_with = "auto")]
+        output: Option<String>,
+
+        /// Save into the auto-snapshot directory, pruning old snapshots
+        #[arg(long)]
+        auto: bool,
+    },
+
+    /// Recreate sessions from a snapshot
+    Restore {
+        /// Snapshot or template file (default: the newest auto-snapshot)
+        file: Option<String>,
+
+        /// Only restore these sessions
+        #[arg(short, long = "session", value_name = "NAME")]
+        sessions: Vec<String>,
+    },
+
+    /// Run a command in a window and wait for it to finish
+    ///
+    /// Prints the command's output and exits with its status, 0 or 1 when
+    /// --expect or --fail matched, or 124 on timeout.
+    Run {
+        /// Session name or ID
+        session: String,
+
+        /// Window number (default: window 0)
+        #[arg(short, long)]
+        window: Option<usize>,
+
+        /// Give up after this many seconds
+        #[arg(short, long, value_name = "SECS")]
+        timeout: Option<u64>,
+
+        /// Stop with status 0 when output matches this regex
+        #[arg(short, long, value_name = "REGEX")]
+        expect: Option<String>,
+
+        /// Stop with status 1 when output matches this regex
+        #[arg(long, value_name = "REGEX")]
+        fail: Option<String>,
+
+        /// Stop when the last line matches this shell prompt regex
+        #[arg(long, value_name = "REGEX")]
+        prompt: Option<String>,
+
+        /// Don't echo a marker with the exit status after the command
+        #[arg(long)]
+        no_sentinel: bool,
+
+        /// Poll interval in milliseconds
+        #[arg(short, long, default_value_t = 500, value_name = "MS")]
+        interval: u64,
+
+        /// Command to run (after `--`)
+        #[arg(last = true, required = true)]
+        command: Vec<String>,
+    },
+
+    /// Type the same input into several sessions or windows
+    ///
+    /// Each line read from stdin (or the text after `--`) is sent to every
+    /// target, followed by Enter.
+    Broadcast {
+        /// Comma-separated targets: name[@host][:window]
+        #[arg(short, long, required = true)]
+        sessions: String,
+
+        /// Don't press Enter after each line
+        #[arg(long)]
+        no_enter: bool,
+
+        /// Text to type instead of reading stdin (after `--`)
+        #[arg(last = true)]
+        text: Vec<String>,
+    },
+
     /// Rename a screen session
     Rename {
         /// Current session name or ID
@@ -114,6 +322,91 @@ pub enum Commands {
     },
 }
 
+/// Template subcommands
+#[derive(Subcommand, Debug)]
+pub enum TemplateCommand {
+    /// Save a running local session's layout as a template
+    Save {
+        /// Session name or ID
+        session: String,
+
+        /// Template name (default: the session name)
+        #[arg(short, long)]
+        name: Option<String>,
+
+        /// Overwrite an existing template
+        #[arg(short, long)]
+        force: bool,
+    },
+
+    /// Convert a tmuxinator or tmuxp project file into a template
+    Import {
+        /// Project file
+        file: String,
+
+        /// Template name (default: the project name)
+        #[arg(short, long)]
+        name: Option<String>,
+
+        /// Overwrite an existing template
+        #[arg(short, long)]
+        force: bool,
+    },
+}
+
+/// Window management subcommands
+#[derive(Subcommand, Debug)]
+pub enum WindowCommand {
+    /// Create a window
+    New {
+        /// Session name or ID
+        session: String,
+
+        /// Window title
+        #[arg(short, long)]
+        name: Option<String>,
+    },
+
+    /// Rename a window
+    Rename {
+        /// Session name or ID
+        session: String,
+
+        /// Window number
+        window: usize,
+
+        /// New window title
+        #[arg(value_name = "NEW_NAME")]
+        new_name: String,
+    },
+
+    /// Kill a window
+    Kill {
+        /// Session name or ID
+        session: String,
+
+        /// Window number
+        window: usize,
+
+        /// Skip confirmation prompt
+        #[arg(short, long)]
+        force: bool,
+    },
+
+    /// Give a window another number (swaps with a window already using it)
+    Move {
+        /// Session name or ID
+        session: String,
+
+        /// Window number
+        window: usize,
+
+        /// New window number
+        #[arg(value_name = "NUMBER")]
+        number: usize,
+    },
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
@@ -130,11 +423,14 @@ async fn main() -> Result<()> {
     let config = config::Settings::load(cli.config.as_deref())?;
 
     match cli.command {
-        Some(Commands::List { all }) => cmd_list(&config, all, cli.host.as_deref()).await,
+        Some(Commands::List { all, filter }) => {
+            cmd_list(&config, all, filter.as_deref(), cli.host.as_deref()).await
+        }
         Some(Commands::New { name, dir }) => cmd_new(&config, &name, dir.as_deref()).await,
         Some(Commands::Attach { session, spawn }) => {
             cmd_attach(&config, &session, spawn, cli.host.as_deref()).await
         }
+        Some(Commands::Last { spawn }) => cmd_last(&config, spawn).await,
         Some(Commands::Detach { session }) => {
             cmd_detach(&config, &session, cli.host.as_deref()).await
         }
@@ -147,11 +443,139 @@ async fn main() -> Result<()> {
             var,
         }) => cmd_start(&config, &template, name.as_deref(), &var).await,
         Some(Commands::Templates) => cmd_templates(&config).await,
+        Some(Commands::Template { command }) => {
+            if cli.host.is_some() {
+                anyhow::bail!("Templates are stored locally; --host is not supported");
+            }
+            cmd_template(&config, command).await
+        }
         Some(Commands::Windows { session }) => {
             cmd_windows(&config, &session, cli.host.as_deref()).await
         }
+        Some(Commands::Window { command }) => {
+            cmd_window(&config, command, cli.host.as_deref()).await
+        }
+        Some(Commands::Here {
+            name,
+            spawn,
+            detached,
+        }) => cmd_here(&config, name.as_deref(), spawn, detached).await,
+        Some(Commands::Grep {
+            pattern,
+            context,
+            all,
+        }) => cmd_grep(&config, &pattern, context, all, cli.host.as_deref()).await,
+        Some(Commands::Capture {
+            session,
+            window,
+            all_windows,
+            lines,
+            strip_trailing,
+            header,
+            output,
+        }) => {
+            let options = screen::capture::CaptureOptions {
+                lines,
+                strip_trailing,
+                header,
+            };
+            cmd_capture(
+                &config,
+                &session,
+                window,
+                all_windows,
+                options,
+                output.as_deref(),
+                cli.host.as_deref(),
+            )
+            .await
+        }
+        Some(Commands::Watch {
+            session,
+            window,
+            interval,
+        }) => cmd_watch(&config, &session, window, interval, cli.host.as_deref()).await,
+        Some(Commands::Send {
+            session,
+            window,
+            all_windows,
+            no_enter,
+            text,
+        }) => {
+            cmd_send(
+                &config,
+                &session,
+                window,
+                all_windows,
+                !no_enter,
+                &text.join(" "),
+                cli.host.as_deref(),
+            )
+            .await
+        }
+        Some(Commands::Snapshot {
+            sessions,
+            all,
+            output,
+            auto,
+        }) => {
+            if cli.host.is_some() {
+                anyhow::bail!("Snapshots only cover local sessions");
+            }
+            cmd_snapshot(&config, &sessions, all, output.as_deref(), auto).await
+        }
+        Some(Commands::Restore { file, sessions }) => {
+            if cli.host.is_some() {
+                anyhow::bail!("Snapshots only cover local sessions");
+            }
+            cmd_restore(&config, file.as_deref(), &sessions).await
+        }
+        Some(Commands::Run {
+            session,
+            window,
+            timeout,
+            expect,
+            fail,
+            prompt,
+            no_sentinel,
+            interval,
+            command,
+        }) => {
+            let compile =
+                |pattern: Option<String>| pattern.map(|p| regex::Regex::new(&p)).transpose();
+            let wait = screen::run::WaitFor {
+                expect: compile(expect).context("Invalid --expect regex")?,
+                fail: compile(fail).context("Invalid --fail regex")?,
+                prompt: compile(prompt).context("Invalid --prompt regex")?,
+                sentinel: (!no_sentinel).then(screen::run::sentinel_token),
+                timeout: timeout.map(std::time::Duration::from_secs),
+            };
+            if no_sentinel
+                && wait.expect.is_none()
+                && wait.fail.is_none()
+                && wait.prompt.is_none()
+                && wait.timeout.is_none()
+            {
+                anyhow::bail!("--no-sentinel needs --expect, --fail, --prompt or --timeout");
+            }
+            cmd_run(
+                &config,
+                &session,
+                window.unwrap_or(0),
+                &command.join(" "),
+                &wait,
+                interval,
+                cli.host.as_deref(),
+            )
+            .await
+        }
+        Some(Commands::Broadcast {
+            sessions,
+            no_enter,
+            text,
+        }) => cmd_broadcast(&config, &sessions, !no_enter, &text, cli.host.as_deref()).await,
         Some(Commands::Rename { session, new_name }) => {
-            cmd_rename(&session, &new_name, cli.host.as_deref()).await
+            cmd_rename(&config, &session, &new_name, cli.host.as_deref()).await
         }
         None => {
             // Launch TUI
@@ -167,22 +591,23 @@ async fn run_tui(config: config::Settings, host: Option<String>) -> Result<()> {
 }
 
 /// List sessions command
-async fn cmd_list(config: &config::Settings, all: bool, host: Option<&str>) -> Result<()> {
-    let sessions = if let Some(host) = host {
-        screen::remote::list_sessions(config, host).await?
-    } else {
-        let mut sessions = screen::local::list_sessions().await?;
-        if all {
-            for host_config in &config.hosts {
-                if let Ok(remote_sessions) =
-                    screen::remote::list_sessions(config, &host_config.name).await
-                {
-                    sessions.extend(remote_sessions);
-                }
-            }
+async fn cmd_list(
+    config: &config::Settings,
+    all: bool,
+    filter: Option<&str>,
+    host: Option<&str>,
+) -> Result<()> {
+    let mut sessions = list_sessions(config, all, host).await?;
+    config::history::History::load_default().sort_sessions(&mut sessions);
+
+    if let Some(query) = filter {
+        let filter = screen::Filter::parse(query);
+        if !filter.is_empty() {
+            screen::local::annotate_sessions(&mut sessions, config.integrations.git_status).await;
+            let matcher = fuzzy_matcher::skim::SkimMatcherV2::default();
+            sessions.retain(|session| filter.matches(session, &matcher).is_some());
         }
-        sessions
-    };
+    }
 
     if sessions.is_empty() {
         println!("No screen sessions found.");
@@ -205,9 +630,380 @@ async fn cmd_list(config: &config::Settings, all: bool, host: Option<&str>) -> R
     Ok(())
 }
 
+/// Sessions on one host, or local sessions plus (with `all`) every configured host
+async fn list_sessions(
+    config: &config::Settings,
+    all: bool,
+    host: Option<&str>,
+) -> Result<Vec<screen::Session>> {
+    let sessions = if let Some(host) = host {
+        screen::remote::list_sessions(config, host).await?
+    } else {
+        let mut sessions = screen::local::list_sessions().await?;
+        if all {
+            for host_config in &config.hosts {
+                if let Ok(remote_sessions) =
+                    screen::remote::list_sessions(config, &host_config.name).await
+                {
+                    sessions.extend(remote_sessions);
+                }
+            }
+        }
+        sessions
+    };
+
+    Ok(sessions)
+}
+
+/// Grep the scrollback of every session
+async fn cmd_grep(
+    config: &config::Settings,
+    pattern: &str,
+    context: usize,
+    all: bool,
+    host: Option<&str>,
+) -> Result<()> {
+    let regex = screen::scrollback::smart_case_regex(pattern)?;
+    let sessions = list_sessions(config, all, host).await?;
+    let report = screen::grep::grep(config, &sessions, &regex, context).await;
+
+    for (i, hit) in report.hits.iter().enumerate() {
+        if context > 0 && i > 0 {
+            println!("--");
+        }
+
+        let prefix = format!("{}:{}", hit.session.display_name(), hit.window.number);
+        let first = hit.hit.line - hit.hit.before.len();
+        for (offset, line) in hit.hit.before.iter().enumerate() {
+            println!("{}-{}- {}", prefix, first + offset + 1, line);
+        }
+        println!("{}: {}", hit.location(), hit.text);
+        for (offset, line) in hit.hit.after.iter().enumerate() {
+            println!("{}-{}- {}", prefix, hit.hit.line + offset + 2, line);
+        }
+    }
+
+    for (target, error) in &report.errors {
+        eprintln!("{}: {}", target, error);
+    }
+    eprintln!(
+        "{} match(es) in {} window(s) of {} session(s)",
+        report.hits.len(),
+        report.windows,
+        sessions.iter().filter(|s| !s.is_virtual()).count()
+    );
+
+    // Like grep, exit with 1 when nothing matched
+    if report.hits.is_empty() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Capture the scrollback of one or all windows of a session
+async fn cmd_capture(
+    config: &config::Settings,
+    session: &str,
+    window: Option<usize>,
+    all_windows: bool,
+    mut options: screen::capture::CaptureOptions,
+    output: Option<&str>,
+    host: Option<&str>,
+) -> Result<()> {
+    use screen::capture::{render, CaptureSource};
+
+    // Window titles label the header; capturing works without them
+    let windows = screen::window::list(config, host, session)
+        .await
+        .unwrap_or_default();
+
+    let numbers: Vec<Option<usize>> = if all_windows {
+        if windows.is_empty() {
+            anyhow::bail!("No windows found in session '{}'", session);
+        }
+        options.header = true;
+        windows.iter().map(|w| Some(w.number)).collect()
+    } else {
+        vec![window]
+    };
+
+    let now = chrono::Local::now();
+    let mut text = String::new();
+    for (i, number) in numbers.iter().enumerate() {
+        let preview = screen::window::capture(config, host, session, *number).await?;
+        let name = number
+            .and_then(|n| windows.iter().find(|w| w.number == n))
+            .map_or("", |w| w.name.as_str());
+        let source = CaptureSource {
+            session,
+            window: number.map(|n| (n, name)),
+            host,
+        };
+
+        if i > 0 {
+            text.push('\n');
+        }
+        text.push_str(&render(&source, &preview.lines, &options, now));
+    }
+
+    match output {
+        None | Some("-") => print!("{}", text),
+        Some(path) => {
+            std::fs::write(path, &text).with_context(|| format!("Failed to write {}", path))?;
+            eprintln!("Wrote {} lines to {}", text.lines().count(), path);
+        }
+    }
+    Ok(())
+}
+
+/// Show a window's output until `q` is pressed
+///
+/// Keys never reach the session, so this is safe on production consoles.
+async fn cmd_watch(
+    config: &config::Settings,
+    session: &str,
+    window: Option<usize>,
+    interval: u64,
+    host: Option<&str>,
+) -> Result<()> {
+    use crossterm::event::{self, Event, KeyCode, KeyModifiers};
+    use std::time::{Duration, Instant};
+
+    let theme =
+        ui::theme::Theme::from_settings(config).unwrap_or_else(|_| ui::theme::Theme::dark());
+    let interval = Duration::from_millis(interval.max(100));
+    let title = format!(
+        " sesh watch - {}{}:{} (every {} ms) ",
+        session,
+        host.map(|h| format!("@{}", h)).unwrap_or_default(),
+        window.unwrap_or(0),
+        interval.as_millis()
+    );
+
+    let mut terminal = crate::event::Terminal::new(false)?;
+    let mut preview = screen::Preview::new();
+
+    let result: Result<()> = async {
+        loop {
+            let error = match screen::window::capture(config, host, session, window).await {
+                Ok(mut captured) => {
+                    captured.diff_against(&preview);
+                    preview = captured;
+                    None
+                }
+                Err(e) => Some(e.to_string()),
+            };
+
+            terminal.backend.draw(|frame| {
+                ui::watch::draw(frame, &title, &preview, error.as_deref(), &theme);
+            })?;
+
+            let deadline = Instant::now() + interval;
+            while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+                if !event::poll(remaining.min(Duration::from_millis(50)))? {
+                    continue;
+                }
+                if let Event::Key(key) = event::read()? {
+                    let ctrl_c = key.code == KeyCode::Char('c')
+                        && key.modifiers.contains(KeyModifiers::CONTROL);
+                    if matches!(key.code, KeyCode::Char('q') | KeyCode::Esc) || ctrl_c {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+    }
+    .await;
+
+    terminal.restore()?;
+    result
+}
+
+/// Type text into one or all windows of a session
+async fn cmd_send(
+    config: &config::Settings,
+    session: &str,
+    window: Option<usize>,
+    all_windows: bool,
+    enter: bool,
+    text: &str,
+    host: Option<&str>,
+) -> Result<()> {
+    let windows: Vec<Option<usize>> = if all_windows {
+        screen::window::list(config, host, session)
+            .await?
+            .iter()
+            .map(|w| Some(w.number))
+            .collect()
+    } else {
+        vec![window]
+    };
+
+    for window in windows {
+        screen::send::send(config, host, session, window, text, enter).await?;
+        match window {
+            Some(number) => println!("Sent to {}:{}", session, number),
+            None => println!("Sent to {}", session),
+        }
+    }
+    Ok(())
+}
+
+/// Snapshot command
+async fn cmd_snapshot(
+    config: &config::Settings,
+    names: &[String],
+    all: bool,
+    output: Option<&str>,
+    auto: bool,
+) -> Result<()> {
+    if names.is_empty() && !all && !auto {
+        anyhow::bail!("Name the sessions to snapshot, or use --all");
+    }
+
+    let mut sessions = screen::local::list_sessions().await?;
+    if !names.is_empty() {
+        for name in names {
+            if !sessions.iter().any(|s| &s.name == name || &s.id == name) {
+                anyhow::bail!("Session '{}' not found", name);
+            }
+        }
+        sessions.retain(|s| names.iter().any(|n| n == &s.name || n == &s.id));
+    }
+
+    let snapshot = config::snapshot::Snapshot::capture(&sessions).await;
+    let count = snapshot.sessions.len();
+
+    if auto {
+        let path = config::snapshot::save_auto(&snapshot, config.screen.snapshot_keep)?;
+        eprintln!("Saved {} sessions to {}", count, path.display());
+        return Ok(());
+    }
+
+    match output {
+        Some(path) if path != "-" => {
+            snapshot.save(std::path::Path::new(path))?;
+            eprintln!("Saved {} sessions to {}", count, path);
+        }
+        _ => print!("{}", snapshot.to_yaml()?),
+    }
+    Ok(())
+}
+
+/// Restore command
+///
+/// Exits with status 1 if any session could not be restored.
+async fn cmd_restore(
+    config: &config::Settings,
+    file: Option<&str>,
+    names: &[String],
+) -> Result<()> {
+    let path = match file {
+        Some(file) => std::path::PathBuf::from(file),
+        None => config::snapshot::auto_snapshots()
+            .pop()
+            .context("No auto-snapshots found; give a snapshot file")?,
+    };
+
+    let mut snapshot = config::snapshot::Snapshot::load(&path)?;
+    if !names.is_empty() {
+        snapshot.sessions.retain(|t| names.contains(&t.name));
+    }
+    if snapshot.sessions.is_empty() {
+        anyhow::bail!("No sessions to restore in {}", path.display());
+    }
+
+    let running = screen::local::list_sessions().await?;
+    let results = config::snapshot::restore(config, &snapshot, &running).await;
+
+    let mut failed = 0;
+    for result in &results {
+        match &result.result {
+            Ok(message) => println!("{}: {}", result.label, message),
+            Err(e) => {
+                eprintln!("{}: {}", result.label, e);
+                failed += 1;
+            }
+        }
+    }
+
+    if failed > 0 {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Run command
+///
+/// Exits with the status derived from how the command finished.
+async fn cmd_run(
+    config: &config::Settings,
+    session: &str,
+    window: usize,
+    command: &str,
+    wait: &screen::run::WaitFor,
+    interval: u64,
+    host: Option<&str>,
+) -> Result<()> {
+    let interval = std::time::Duration::from_millis(interval.max(100));
+    let result = screen::run::run(config, host, session, window, command, wait, interval).await?;
+
+    for line in &result.output {
+        println!("{}", line);
+    }
+    eprintln!("{}:{}: {}", session, window, result.outcome.describe());
+
+    std::process::exit(result.outcome.status());
+}
+
+/// Broadcast command
+///
+/// Exits with status 1 if any line failed to reach any target.
+async fn cmd_broadcast(
+    config: &config::Settings,
+    sessions: &str,
+    enter: bool,
+    text: &[String],
+    host: Option<&str>,
+) -> Result<()> {
+    let targets = screen::broadcast::parse_targets(sessions, host)?;
+    if targets.is_empty() {
+        anyhow::bail!("No targets given");
+    }
+
+    let lines: Vec<String> = if text.is_empty() {
+        std::io::stdin()
+            .lines()
+            .collect::<std::io::Result<_>>()
+            .context("Failed to read stdin")?
+    } else {
+        vec![text.join(" ")]
+    };
+
+    let mut failed = 0;
+    for line in &lines {
+        for result in screen::broadcast::broadcast(config, &targets, line, enter).await {
+            match result.result {
+                Ok(message) => println!("{}: {}", result.label, message),
+                Err(e) => {
+                    eprintln!("{}: {}", result.label, e);
+                    failed += 1;
+                }
+            }
+        }
+    }
+
+    if failed > 0 {
+        eprintln!("{} of {} sends failed", failed, lines.len() * targets.len());
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
 /// Create new session command
 async fn cmd_new(config: &config::Settings, name: &str, dir: Option<&str>) -> Result<()> {
     screen::local::create_session(name, dir, config.screen.default_shell.as_deref()).await?;
+    let _ = config::history::History::load_default().record(name, None);
     println!("Created session: {}", name);
     Ok(())
 }
@@ -219,6 +1015,18 @@ async fn cmd_attach(
     spawn: bool,
     host: Option<&str>,
 ) -> Result<()> {
+    // Only sessions that exist go into the history `sesh last` offers
+    let sessions = match host {
+        Some(host) => screen::remote::list_sessions(config, host).await?,
+        None => screen::local::list_sessions().await?,
+    };
+    let found = sessions
+        .iter()
+        .find(|s| s.name == session || s.id == session)
+        .or_else(|| sessions.iter().find(|s| s.name.starts_with(session)))
+        .with_context(|| format!("Session '{}' not found", session))?;
+    let _ = config::history::History::load_default().record(&found.name, host);
+
     if spawn {
         screen::local::attach_spawn(config, session, host).await
     } else {
@@ -226,6 +1034,20 @@ async fn cmd_attach(
     }
 }
 
+/// Attach to the previously used session
+async fn cmd_last(config: &config::Settings, spawn: bool) -> Result<()> {
+    // Inside screen, STY holds the current session as PID.name
+    let current = std::env::var("STY").ok();
+    let current = current.as_deref().map(config::history::strip_pid);
+
+    let history = config::history::History::load_default();
+    let entry = history
+        .previous(current)
+        .ok_or_else(|| anyhow::anyhow!("No previously used session"))?;
+
+    cmd_attach(config, &entry.name, spawn, entry.host.as_deref()).await
+}
+
 /// Detach session command
 async fn cmd_detach(config: &config::Settings, session: &str, host: Option<&str>) -> Result<()> {
     if let Some(host) = host {
@@ -244,17 +1066,9 @@ async fn cmd_kill(
     force: bool,
     host: Option<&str>,
 ) -> Result<()> {
-    if !force {
-        print!("Kill session '{}'? [y/N] ", session);
-        use std::io::{self, Write};
-        io::stdout().flush()?;
-
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
-        if !input.trim().eq_ignore_ascii_case("y") {
-            println!("Aborted.");
-            return Ok(());
-        }
+    if !force && !confirm(&format!("Kill session '{}'?", session))? {
+        println!("Aborted.");
+        return Ok(());
     }
 
     if host.is_some() {
@@ -275,6 +1089,9 @@ async fn cmd_start(
     vars: &[String],
 ) -> Result<()> {
     let tmpl = config::templates::load_template(config, template)?;
+    for warning in &tmpl.warnings {
+        eprintln!("warning: {}", warning);
+    }
     let session_name = name.unwrap_or(&tmpl.name);
 
     // Parse variables
@@ -286,6 +1103,7 @@ async fn cmd_start(
     }
 
     config::templates::create_from_template(config, &tmpl, session_name, &variables).await?;
+    let _ = config::history::History::load_default().record(session_name, None);
     println!(
         "Created session '{}' from template '{}'",
         session_name, template
@@ -315,14 +1133,68 @@ async fn cmd_templates(config: &config::Settings) -> Result<()> {
     Ok(())
 }
 
+/// Template subcommands
+async fn cmd_template(config: &config::Settings, command: TemplateCommand) -> Result<()> {
+    match command {
+        TemplateCommand::Save {
+            session,
+            name,
+            force,
+        } => {
+            let sessions = screen::local::list_sessions().await?;
+            let session = sessions
+                .iter()
+                .find(|s| s.name == session || s.id == session)
+                .with_context(|| format!("Session '{}' not found", session))?;
+
+            let name = name.as_deref().unwrap_or(&session.name);
+            let (template, path) =
+                config::templates::save_from_session(config, session, name, force).await?;
+            println!(
+                "Saved template '{}' ({} windows) to {}",
+                template.name,
+                template.windows.len(),
+                path.display()
+            );
+        }
+        TemplateCommand::Import { file, name, force } => {
+            let mut imported = config::tmux::import_file(std::path::Path::new(&file))?;
+            for warning in &imported.template.warnings {
+                eprintln!("warning: {}", warning);
+            }
+            if let Some(name) = name {
+                imported.template.name = name;
+            }
+
+            let template = &imported.template;
+            let path = config::templates::save_template(config, template, force)?;
+            println!(
+                "Imported {} project '{}' ({} windows) to {}",
+                imported.format.name(),
+                template.name,
+                template.windows.len(),
+                path.display()
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Ask a yes/no question on the terminal
+fn confirm(question: &str) -> Result<bool> {
+    use std::io::{self, Write};
+
+    print!("{} [y/N] ", question);
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(input.trim().eq_ignore_ascii_case("y"))
+}
+
 /// Show windows command
-async fn cmd_windows(_config: &config::Settings, session: &str, host: Option<&str>) -> Result<()> {
-    let windows = if host.is_some() {
-        // TODO: Implement remote windows
-        anyhow::bail!("Remote windows not yet implemented");
-    } else {
-        screen::local::list_windows(session).await?
-    };
+async fn cmd_windows(config: &config::Settings, session: &str, host: Option<&str>) -> Result<()> {
+    let windows = screen::window::list(config, host, session).await?;
 
     if windows.is_empty() {
         println!("No windows found in session '{}'.", session);
@@ -341,12 +1213,83 @@ async fn cmd_windows(_config: &config::Settings, session: &str, host: Option<&st
     Ok(())
 }
 
-/// Rename session command
-async fn cmd_rename(session: &str, new_name: &str, host: Option<&str>) -> Result<()> {
-    if host.is_some() {
-        anyhow::bail!("Remote rename not yet implemented");
+/// Window management command
+async fn cmd_window(
+    config: &config::Settings,
+    command: WindowCommand,
+    host: Option<&str>,
+) -> Result<()> {
+    use screen::window::WindowOp;
+
+    let (session, op) = match command {
+        WindowCommand::New { session, name } => (session, WindowOp::New(name)),
+        WindowCommand::Rename {
+            session,
+            window,
+            new_name,
+        } => (session, WindowOp::Rename(window, new_name)),
+        WindowCommand::Kill {
+            session,
+            window,
+            force,
+        } => {
+            if !force && !confirm(&format!("Kill window {} of '{}'?", window, session))? {
+                println!("Aborted.");
+                return Ok(());
+            }
+            (session, WindowOp::Kill(window))
+        }
+        WindowCommand::Move {
+            session,
+            window,
+            number,
+        } => (session, WindowOp::Move(window, number)),
+    };
+
+    screen::window::run(config, host, &session, &op).await?;
+    println!("{}", op.done_message());
+    Ok(())
+}
+
+/// Create or attach to the current project's session
+async fn cmd_here(
+    config: &config::Settings,
+    name: Option<&str>,
+    spawn: bool,
+    detached: bool,
+) -> Result<()> {
+    let cwd = std::env::current_dir()?;
+    let project = config::project::Project::find(&cwd)?;
+    let session_name = name
+        .map(String::from)
+        .unwrap_or_else(|| project.session_name());
+
+    let sessions = screen::local::list_sessions().await?;
+    if sessions.iter().any(|s| s.name == session_name) {
+        println!("Attaching to existing session: {}", session_name);
     } else {
-        screen::local::rename_session(session, new_name).await?;
+        project.create_session(config, &session_name).await?;
+        println!("Created session: {}", session_name);
+    }
+
+    if detached {
+        let _ = config::history::History::load_default().record(&session_name, None);
+        return Ok(());
+    }
+
+    cmd_attach(config, &session_name, spawn, None).await
+}
+
+/// Rename session command
+async fn cmd_rename(
+    config: &config::Settings,
+    session: &str,
+    new_name: &str,
+    host: Option<&str>,
+) -> Result<()> {
+    match host {
+        Some(host) => screen::remote::rename_session(config, host, session, new_name).await?,
+        None => screen::local::rename_session(session, new_name).await?,
     }
     println!("Renamed '{}' -> '{}'", session, new_name);
     Ok(())
@@ -0,0 +1,137 @@
+//! Broadcasting input
+//!
+//! Types the same text into several sessions or windows at once, possibly
+//! on different hosts, and reports the outcome for each.
+
+use anyhow::{bail, Result};
+use tokio::task::JoinSet;
+
+use super::bulk::{BulkResult, Target};
+use super::send;
+use crate::config::Settings;
+
+/// A session, or one window of it, that broadcast text is typed into
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BroadcastTarget {
+    /// Session the text goes to
+    pub session: Target,
+    /// Window number (None for the session's current window)
+    pub window: Option<usize>,
+}
+
+impl BroadcastTarget {
+    /// Parse a `name[@host][:window]` target
+    ///
+    /// `host` applies to targets that don't name one.
+    pub fn parse(spec: &str, host: Option<&str>) -> Result<Self> {
+        let spec = spec.trim();
+        let (rest, window) = match spec.rsplit_once(':') {
+            Some((rest, number)) if number.bytes().all(|b| b.is_ascii_digit()) => {
+                (rest, number.parse().ok())
+            }
+            _ => (spec, None),
+        };
+        let (name, host) = match rest.rsplit_once('@') {
+            Some((name, host)) if !host.is_empty() => (name, Some(host)),
+            _ => (rest, host),
+        };
+
+        if name.is_empty() {
+            bail!("Invalid target '{}', expected name[@host][:window]", spec);
+        }
+
+        Ok(Self {
+            session: Target {
+                id: name.to_string(),
+                name: name.to_string(),
+                host: host.map(String::from),
+            },
+            window,
+        })
+    }
+
+    /// Display label, e.g. "api@prod:2"
+    pub fn label(&self) -> String {
+        match self.window {
+            Some(number) => format!("{}:{}", self.session.label(), number),
+            None => self.session.label(),
+        }
+    }
+}
+
+/// Parse a comma-separated list of targets
+pub fn parse_targets(list: &str, host: Option<&str>) -> Result<Vec<BroadcastTarget>> {
+    list.split(',')
+        .filter(|spec| !spec.trim().is_empty())
+        .map(|spec| BroadcastTarget::parse(spec, host))
+        .collect()
+}
+
+/// Type a line into every target, in parallel
+///
+/// Results are in target order.
+pub async fn broadcast(
+    config: &Settings,
+    targets: &[BroadcastTarget],
+    text: &str,
+    enter: bool,
+) -> Vec<BulkResult> {
+    let mut tasks = JoinSet::new();
+
+    for (index, target) in targets.iter().enumerate() {
+        let config = config.clone();
+        let target = target.clone();
+        let text = text.to_string();
+
+        tasks.spawn(async move {
+            let session = &target.session;
+            let result = send::send(
+                &config,
+                session.host.as_deref(),
+                &session.id,
+                target.window,
+                &text,
+                enter,
+            )
+            .await
+            .map(|()| "sent".to_string())
+            .map_err(|e| e.to_string());
+
+            (index, result)
+        });
+    }
+
+    let mut results: Vec<Option<std::result::Result<String, String>>> = vec![None; targets.len()];
+    while let Some(joined) = tasks.join_next().await {
+        if let Ok((index, result)) = joined {
+            results[index] = Some(result);
+        }
+    }
+
+    targets
+        .iter()
+        .zip(results)
+        .map(|(target, result)| BulkResult {
+            label: target.label(),
+            result: result.unwrap_or_else(|| Err("task failed".to_string())),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_targets() {
+        let targets = parse_targets("api, web@prod:2,db:1,", Some("dev")).unwrap();
+        let labels: Vec<String> = targets.iter().map(BroadcastTarget::label).collect();
+        assert_eq!(labels, vec!["api@dev", "web@prod:2", "db@dev:1"]);
+        assert_eq!(targets[1].window, Some(2));
+        assert_eq!(targets[1].session.id, "web");
+
+        let local = BroadcastTarget::parse("build:x", None).unwrap();
+        assert_eq!(local.label(), "build:x");
+        assert!(BroadcastTarget::parse("@prod:1", None).is_err());
+    }
+}
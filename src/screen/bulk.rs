@@ -0,0 +1,258 @@
+//! Bulk session operations
+//!
+//! Applies one operation to several sessions (local or remote) and collects
+//! a result per session.
+
+use anyhow::{bail, Context, Result};
+use regex::Regex;
+
+use super::types::Session;
+use super::{local, remote};
+use crate::config::Settings;
+
+/// A session an operation is applied to
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Target {
+    /// Full session identifier (PID.name)
+    pub id: String,
+    /// Session name
+    pub name: String,
+    /// Host the session is on (None for local)
+    pub host: Option<String>,
+}
+
+impl Target {
+    /// Build a target from a session
+    pub fn from_session(session: &Session) -> Self {
+        Self {
+            id: session.id.clone(),
+            name: session.name.clone(),
+            host: session.host.clone(),
+        }
+    }
+
+    /// Check whether this target refers to a session
+    pub fn is_session(&self, session: &Session) -> bool {
+        self.id == session.id && self.host == session.host
+    }
+
+    /// Display label (includes host if remote)
+    pub fn label(&self) -> String {
+        match &self.host {
+            Some(host) => format!("{}@{}", self.name, host),
+            None => self.name.clone(),
+        }
+    }
+}
+
+/// Operation applied to every target
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BulkOp {
+    /// Kill the session
+    Kill,
+    /// Detach the session
+    Detach,
+    /// Rename using a pattern (see [`rename_with_pattern`])
+    Rename(String),
+    /// Send a screen command (`screen -X`)
+    Command(String),
+}
+
+impl BulkOp {
+    /// Verb used in confirmation prompts
+    pub fn verb(&self) -> &'static str {
+        match self {
+            BulkOp::Kill => "Kill",
+            BulkOp::Detach => "Detach",
+            BulkOp::Rename(_) => "Rename",
+            BulkOp::Command(_) => "Send command to",
+        }
+    }
+}
+
+/// Outcome of an operation on one target
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BulkResult {
+    /// Target label
+    pub label: String,
+    /// Success message or error
+    pub result: std::result::Result<String, String>,
+}
+
+impl BulkResult {
+    /// Check whether the operation succeeded
+    pub fn is_ok(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+/// Summarize targets for a confirmation prompt, e.g. "Kill 5 sessions (a, b, c, +2 more)?"
+pub fn confirm_message(op: &BulkOp, targets: &[Target]) -> String {
+    const SHOWN: usize = 3;
+
+    let mut names: Vec<String> = targets.iter().take(SHOWN).map(Target::label).collect();
+    if targets.len() > SHOWN {
+        names.push(format!("+{} more", targets.len() - SHOWN));
+    }
+
+    let noun = if targets.len() == 1 {
+        "session"
+    } else {
+        "sessions"
+    };
+    let detail = match op {
+        BulkOp::Rename(pattern) => format!(" to '{}'", pattern),
+        BulkOp::Command(command) => format!(": '{}'", command),
+        _ => String::new(),
+    };
+
+    format!(
+        "{} {} {} ({}){}?",
+        op.verb(),
+        targets.len(),
+        noun,
+        names.join(", "),
+        detail
+    )
+}
+
+/// Apply an operation to every target, in order
+pub async fn run(config: &Settings, op: &BulkOp, targets: &[Target]) -> Vec<BulkResult> {
+    let mut results = Vec::with_capacity(targets.len());
+
+    for (i, target) in targets.iter().enumerate() {
+        let result = run_one(config, op, target, i + 1)
+            .await
+            .map_err(|e| e.to_string());
+        results.push(BulkResult {
+            label: target.label(),
+            result,
+        });
+    }
+
+    results
+}
+
+/// Apply an operation to a single target
+async fn run_one(config: &Settings, op: &BulkOp, target: &Target, index: usize) -> Result<String> {
+    let host = target.host.as_deref();
+
+    match op {
+        BulkOp::Kill => {
+            match host {
+                Some(host) => remote::kill_session(config, host, &target.id).await?,
+                None => local::kill_session(&target.id).await?,
+            }
+            Ok("killed".to_string())
+        }
+        BulkOp::Detach => {
+            match host {
+                Some(host) => remote::detach_session(config, host, &target.id).await?,
+                None => local::detach_session(&target.id).await?,
+            }
+            Ok("detached".to_string())
+        }
+        BulkOp::Rename(pattern) => {
+            let new_name = rename_with_pattern(pattern, &target.name, index)?;
+            match host {
+                Some(host) => remote::rename_session(config, host, &target.id, &new_name).await?,
+                None => local::rename_session(&target.id, &new_name).await?,
+            }
+            Ok(format!("renamed to '{}'", new_name))
+        }
+        BulkOp::Command(command) => {
+            match host {
+                Some(host) => remote::send_command(config, host, &target.id, None, command).await?,
+                None => local::send_command(&target.id, None, command).await?,
+            }
+            Ok("sent".to_string())
+        }
+    }
+}
+
+/// Compute a new session name from a rename pattern
+///
+/// Patterns are either a substitution `s/regex/replacement/` applied to the
+/// name, or a template where `{name}` is the current name and `{n}` the
+/// 1-based position of the session in the selection.
+pub fn rename_with_pattern(pattern: &str, name: &str, index: usize) -> Result<String> {
+    let new_name = if let Some(body) = pattern.strip_prefix("s/") {
+        let body = body.strip_suffix('/').unwrap_or(body);
+        let (from, to) = match body.split_once('/') {
+            Some(parts) => parts,
+            None => bail!(
+                "Invalid substitution '{}', expected s/regex/replacement/",
+                pattern
+            ),
+        };
+        let re = Regex::new(from).context("Invalid rename regex")?;
+        re.replace_all(name, to).into_owned()
+    } else {
+        pattern
+            .replace("{name}", name)
+            .replace("{n}", &index.to_string())
+    };
+
+    if new_name.is_empty() {
+        bail!("Pattern produced an empty name");
+    }
+
+    Ok(new_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(name: &str, host: Option<&str>) -> Target {
+        Target {
+            id: format!("1.{}", name),
+            name: name.to_string(),
+            host: host.map(String::from),
+        }
+    }
+
+    #[test]
+    fn test_rename_template() {
+        assert_eq!(
+            rename_with_pattern("old-{name}", "api", 1).unwrap(),
+            "old-api"
+        );
+        assert_eq!(
+            rename_with_pattern("sprint-{n}", "api", 3).unwrap(),
+            "sprint-3"
+        );
+    }
+
+    #[test]
+    fn test_rename_substitution() {
+        assert_eq!(
+            rename_with_pattern("s/^feat-//", "feat-login", 1).unwrap(),
+            "login"
+        );
+        assert_eq!(
+            rename_with_pattern("s/(\\w+)-(\\w+)/$2-$1", "api-dev", 1).unwrap(),
+            "dev-api"
+        );
+        assert!(rename_with_pattern("s/^.*$//", "api", 1).is_err());
+        assert!(rename_with_pattern("s/nope", "api", 1).is_err());
+    }
+
+    #[test]
+    fn test_confirm_message() {
+        let targets = vec![
+            target("a", None),
+            target("b", Some("prod")),
+            target("c", None),
+            target("d", None),
+        ];
+        assert_eq!(
+            confirm_message(&BulkOp::Kill, &targets),
+            "Kill 4 sessions (a, b@prod, c, +1 more)?"
+        );
+        assert_eq!(
+            confirm_message(&BulkOp::Command("select 1".to_string()), &targets[..1]),
+            "Send command to 1 session (a): 'select 1'?"
+        );
+    }
+}
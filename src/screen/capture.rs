@@ -0,0 +1,138 @@
+//! Scrollback capture
+//!
+//! Formats captured window content for `sesh capture`, optionally with a
+//! header identifying where and when it was captured.
+
+use chrono::{DateTime, Local};
+
+/// How captured lines are trimmed and labelled
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CaptureOptions {
+    /// Keep only the last N lines
+    pub lines: Option<usize>,
+    /// Drop blank lines at the end of the capture
+    pub strip_trailing: bool,
+    /// Start with a header naming the session, window, host and time
+    pub header: bool,
+}
+
+/// Where a capture came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaptureSource<'a> {
+    /// Session name
+    pub session: &'a str,
+    /// Window number and title, if known
+    pub window: Option<(usize, &'a str)>,
+    /// Remote host (None for local)
+    pub host: Option<&'a str>,
+}
+
+/// Apply the trimming options to captured lines
+pub fn trim_lines<'a>(lines: &'a [String], options: &CaptureOptions) -> &'a [String] {
+    let end = if options.strip_trailing {
+        lines
+            .iter()
+            .rposition(|line| !line.trim().is_empty())
+            .map_or(0, |i| i + 1)
+    } else {
+        lines.len()
+    };
+
+    let start = options.lines.map_or(0, |n| end.saturating_sub(n));
+    &lines[start..end]
+}
+
+/// Header line describing a capture
+pub fn header(source: &CaptureSource, at: DateTime<Local>) -> String {
+    let window = match source.window {
+        Some((number, name)) if !name.is_empty() => format!("{} ({})", number, name),
+        Some((number, _)) => number.to_string(),
+        None => "default".to_string(),
+    };
+
+    format!(
+        "# session: {}  window: {}  host: {}  captured: {}",
+        source.session,
+        window,
+        source.host.unwrap_or("local"),
+        at.format("%Y-%m-%d %H:%M:%S %z")
+    )
+}
+
+/// Render a capture as text ending in a newline
+pub fn render(
+    source: &CaptureSource,
+    lines: &[String],
+    options: &CaptureOptions,
+    at: DateTime<Local>,
+) -> String {
+    let mut out = String::new();
+    if options.header {
+        out.push_str(&header(source, at));
+        out.push('\n');
+    }
+    for line in trim_lines(lines, options) {
+        out.push_str(line);
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn lines() -> Vec<String> {
+        ["$ make", "ok", "done", "", ""]
+            .iter()
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_trim_lines() {
+        let lines = lines();
+        let strip = CaptureOptions {
+            strip_trailing: true,
+            ..Default::default()
+        };
+        assert_eq!(trim_lines(&lines, &strip).len(), 3);
+
+        let last_two = CaptureOptions {
+            lines: Some(2),
+            ..strip
+        };
+        assert_eq!(trim_lines(&lines, &last_two), &lines[1..3]);
+
+        let unstripped = CaptureOptions {
+            lines: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(trim_lines(&lines, &unstripped), &lines[3..]);
+    }
+
+    #[test]
+    fn test_render_with_header() {
+        let at = Local.with_ymd_and_hms(2024, 5, 1, 12, 30, 0).unwrap();
+        let source = CaptureSource {
+            session: "api",
+            window: Some((2, "logs")),
+            host: Some("prod"),
+        };
+        let options = CaptureOptions {
+            lines: Some(1),
+            strip_trailing: true,
+            header: true,
+        };
+
+        let text = render(&source, &lines(), &options, at);
+        let mut rendered = text.lines();
+        let first = rendered.next().unwrap();
+        assert!(first.starts_with(
+            "# session: api  window: 2 (logs)  host: prod  captured: 2024-05-01 12:30:00"
+        ));
+        assert_eq!(rendered.next(), Some("done"));
+        assert_eq!(rendered.next(), None);
+    }
+}
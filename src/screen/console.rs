@@ -0,0 +1,154 @@
+//! Screen command console
+//!
+//! Runs screen commands typed at the TUI's `:` prompt in a session or
+//! window, completes command names, and returns replies to queries.
+
+use anyhow::Result;
+
+use super::{local, parser, remote};
+use crate::config::Settings;
+
+/// Screen commands offered for completion, separated by whitespace
+const COMMANDS: &str = "\
+    acladd aclchg acldel aclgrp aclumask activity addacl allpartial altscreen \
+    at attrcolor autodetach autonuke backtick bce bell_msg bind bindkey \
+    blanker blankerprg break breaktype bufferfile c1 caption chacl charset \
+    chdir cjkwidth clear collapse colon command compacthist console copy crlf \
+    debug defautonuke defbce defbreaktype defc1 defcharset defencoding \
+    defescape defflow defgr defhstatus deflog deflogin defmode defmonitor \
+    defmousetrack defnonblock defobuflimit defscrollback defshell defsilence \
+    defslowpaste defutf8 defwrap defwritelock detach digraph dinfo displays \
+    dumptermcap echo encoding escape eval exec fit flow focus focusminsize gr \
+    group hardcopy hardcopy_append hardcopydir hardstatus height help history \
+    hstatus idle ignorecase info kill lastmsg layout license lockscreen log \
+    logfile login logtstamp mapdefault mapnotnext maptimeout markkeys maxwin \
+    meta monitor mousetrack msgminwait msgwait multiuser nethack next \
+    nonblock number obuflimit only other partial password paste pastefont \
+    pow_break pow_detach pow_detach_msg prev printcmd process quit readbuf \
+    readreg redisplay register remove removebuf rendition reset resize screen \
+    scrollback select sessionname setenv setsid shell shelltitle silence \
+    silencewait sleep slowpaste sort source sorendition split startup_message \
+    stuff su suspend term termcap terminfo termcapinfo time title truecolor \
+    unbindall unsetenv utf8 vbell vbell_msg vbellwait version wall width \
+    windowlist windows wrap writebuf writelock xoff xon zmodem zombie";
+
+/// Commands that reply to `screen -Q`
+const QUERIES: &[&str] = &[
+    "echo",
+    "info",
+    "lastmsg",
+    "number",
+    "select",
+    "sessionname",
+    "time",
+    "title",
+    "version",
+    "windows",
+];
+
+/// Check whether a command line is a query whose reply should be shown
+///
+/// Queries other than `echo` only report when given no arguments; with
+/// arguments (`title build`, `select 2`) they change something instead.
+pub fn is_query(command: &str) -> bool {
+    match parser::split_command(command).as_slice() {
+        [name] => QUERIES.contains(&name.as_str()),
+        [name, ..] => name == "echo",
+        [] => false,
+    }
+}
+
+/// Command names starting with a prefix
+pub fn candidates(prefix: &str) -> Vec<&'static str> {
+    COMMANDS
+        .split_whitespace()
+        .filter(|name| name.starts_with(prefix))
+        .collect()
+}
+
+/// Complete the command name being typed
+///
+/// Returns the completed input (with a trailing space when only one command
+/// matches) and the candidates when several still do. Input past the
+/// command name is left alone.
+pub fn complete(input: &str) -> Option<(String, Vec<&'static str>)> {
+    let prefix = input.trim_start();
+    if prefix.contains(char::is_whitespace) {
+        return None;
+    }
+
+    let matches = candidates(prefix);
+    match matches.as_slice() {
+        [] => None,
+        [only] => Some((format!("{} ", only), Vec::new())),
+        [first, rest @ ..] => {
+            let common = rest.iter().fold(first.len(), |len, name| {
+                first
+                    .bytes()
+                    .zip(name.bytes())
+                    .take(len)
+                    .take_while(|(a, b)| a == b)
+                    .count()
+            });
+            Some((first[..common].to_string(), matches))
+        }
+    }
+}
+
+/// Run a command in a session or window on a host (None for local)
+///
+/// Queries return screen's reply; other commands return None.
+pub async fn run(
+    config: &Settings,
+    host: Option<&str>,
+    session: &str,
+    window: Option<usize>,
+    command: &str,
+) -> Result<Option<String>> {
+    if is_query(command) {
+        return match host {
+            Some(host) => remote::query_command(config, host, session, window, command).await,
+            None => local::query_command(session, window, command).await,
+        };
+    }
+
+    match host {
+        Some(host) => remote::send_command(config, host, session, window, command).await?,
+        None => local::send_command(session, window, command).await?,
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_complete() {
+        assert_eq!(
+            complete("defscr"),
+            Some(("defscrollback ".to_string(), Vec::new()))
+        );
+
+        let (input, matches) = complete("hards").unwrap();
+        assert_eq!(input, "hardstatus ");
+        assert!(matches.is_empty());
+
+        let (input, matches) = complete("hardc").unwrap();
+        assert_eq!(input, "hardcopy");
+        assert_eq!(matches, ["hardcopy", "hardcopy_append", "hardcopydir"]);
+
+        assert_eq!(complete("nosuch"), None);
+        assert_eq!(complete("monitor o"), None);
+    }
+
+    #[test]
+    fn test_is_query() {
+        assert!(is_query("info"));
+        assert!(is_query(" title"));
+        assert!(is_query("echo hi there"));
+        assert!(!is_query("title build"));
+        assert!(!is_query("monitor on"));
+        assert!(!is_query(""));
+    }
+}
@@ -0,0 +1,185 @@
+//! Dashboard tiles
+//!
+//! Captures previews of many sessions, or of every window of one session, at
+//! once for the dashboard grid.
+
+use tokio::task::JoinSet;
+
+use super::types::{Preview, Session, Window, WindowActivity};
+use super::{local, remote};
+use crate::config::Settings;
+
+/// One pane of the dashboard grid
+#[derive(Debug, Clone)]
+pub struct Tile {
+    /// Session the tile shows
+    pub session: Session,
+    /// Window the tile shows (None for the session's default window)
+    pub window: Option<Window>,
+    /// Latest captured content
+    pub preview: Preview,
+    /// Activity shown by the tile border
+    pub activity: WindowActivity,
+    /// Error from the last capture, if it failed
+    pub error: Option<String>,
+}
+
+impl Tile {
+    /// Create a tile that has not been captured yet
+    pub fn new(session: Session, window: Option<Window>) -> Self {
+        let activity = window
+            .as_ref()
+            .map(|w| w.activity)
+            .unwrap_or(WindowActivity::Idle);
+
+        Self {
+            session,
+            window,
+            preview: Preview::new(),
+            activity,
+            error: None,
+        }
+    }
+
+    /// Tile title, e.g. "api@prod" or "api:2 logs"
+    pub fn label(&self) -> String {
+        let name = match &self.session.host {
+            Some(host) => format!("{}@{}", self.session.name, host),
+            None => self.session.name.clone(),
+        };
+
+        match &self.window {
+            Some(window) => format!("{}:{} {}", name, window.number, window.name),
+            None => name,
+        }
+    }
+
+    /// Window number captured for this tile
+    pub fn window_number(&self) -> Option<usize> {
+        self.window.as_ref().map(|w| w.number)
+    }
+}
+
+/// Build one tile per real session
+pub fn session_tiles(sessions: &[Session]) -> Vec<Tile> {
+    sessions
+        .iter()
+        .filter(|s| !s.is_virtual())
+        .map(|s| Tile::new(s.clone(), None))
+        .collect()
+}
+
+/// Build one tile per window of a session
+pub fn window_tiles(session: &Session, windows: &[Window]) -> Vec<Tile> {
+    windows
+        .iter()
+        .map(|w| Tile::new(session.clone(), Some(w.clone())))
+        .collect()
+}
+
+/// Number of grid columns for a tile count (as square as possible)
+pub fn grid_columns(count: usize) -> usize {
+    let mut columns = 1;
+    while columns * columns < count {
+        columns += 1;
+    }
+    columns
+}
+
+/// Activity shown for a tile after a refresh
+///
+/// A bell reported by screen wins; otherwise a tile is active when its
+/// content changed since the previous capture.
+pub fn tile_activity(listed: WindowActivity, changed: bool) -> WindowActivity {
+    match listed {
+        WindowActivity::Bell => WindowActivity::Bell,
+        _ if changed => WindowActivity::Active,
+        _ => WindowActivity::Idle,
+    }
+}
+
+/// Capture every tile concurrently and update its content and activity
+pub async fn refresh(config: &Settings, tiles: &mut [Tile]) {
+    let mut tasks = JoinSet::new();
+
+    for (index, tile) in tiles.iter().enumerate() {
+        let config = config.clone();
+        let id = tile.session.id.clone();
+        let host = tile.session.host.clone();
+        let window = tile.window_number();
+
+        tasks.spawn(async move {
+            let result = match host {
+                Some(ref host) => remote::get_preview(&config, host, &id, window).await,
+                None => local::get_preview(&id, window).await,
+            };
+            (index, result)
+        });
+    }
+
+    while let Some(joined) = tasks.join_next().await {
+        let Ok((index, result)) = joined else {
+            continue;
+        };
+        let tile = &mut tiles[index];
+
+        match result {
+            Ok(preview) => {
+                let changed = !tile.preview.lines.is_empty() && tile.preview.lines != preview.lines;
+                let listed = tile.window.as_ref().map(|w| w.activity).unwrap_or_default();
+                tile.activity = tile_activity(listed, changed);
+                tile.preview = preview;
+                tile.error = None;
+            }
+            Err(e) => tile.error = Some(e.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::screen::types::SessionStatus;
+
+    #[test]
+    fn test_grid_columns() {
+        assert_eq!(grid_columns(0), 1);
+        assert_eq!(grid_columns(1), 1);
+        assert_eq!(grid_columns(4), 2);
+        assert_eq!(grid_columns(5), 3);
+        assert_eq!(grid_columns(12), 4);
+    }
+
+    #[test]
+    fn test_tile_activity() {
+        assert_eq!(
+            tile_activity(WindowActivity::Bell, false),
+            WindowActivity::Bell
+        );
+        assert_eq!(
+            tile_activity(WindowActivity::Idle, true),
+            WindowActivity::Active
+        );
+        assert_eq!(
+            tile_activity(WindowActivity::Active, false),
+            WindowActivity::Idle
+        );
+    }
+
+    #[test]
+    fn test_tile_label() {
+        let mut session = Session::new(
+            "1.api".to_string(),
+            "api".to_string(),
+            1,
+            SessionStatus::Detached,
+        );
+        session.host = Some("prod".to_string());
+
+        assert_eq!(Tile::new(session.clone(), None).label(), "api@prod");
+        assert_eq!(
+            Tile::new(session, Some(Window::new(2, "logs".to_string()))).label(),
+            "api@prod:2 logs"
+        );
+    }
+}
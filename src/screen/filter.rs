@@ -0,0 +1,292 @@
+//! Session filter query language
+//!
+//! Parses search queries such as `host:prod status:detached age:>2d foo`.
+//! Plain words fuzzy-match the session name, working directory, git branch
+//! and window names; `key:value` terms filter on session fields.
+
+use chrono::{Duration, Local};
+use fuzzy_matcher::skim::SkimMatcherV2;
+use fuzzy_matcher::FuzzyMatcher;
+
+use super::types::Session;
+
+/// A single filter term
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Term {
+    /// Fuzzy text matched against several fields
+    Text(String),
+    /// Substring of the session name
+    Name(String),
+    /// Substring of the host name ("local" for local sessions)
+    Host(String),
+    /// Prefix of the status (e.g. "det" for detached)
+    Status(String),
+    /// Substring of the git branch
+    Branch(String),
+    /// Substring of the working directory
+    Dir(String),
+    /// Substring of any window name
+    Window(String),
+    /// Session older (`>`) or younger (`<`) than a duration
+    Age { older: bool, duration: Duration },
+}
+
+/// Result of matching a session against a filter
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FilterMatch {
+    /// Combined fuzzy score of the text terms
+    pub score: i64,
+    /// Character indices of the session name matched by text terms
+    pub name_indices: Vec<usize>,
+}
+
+/// Parsed filter query
+#[derive(Debug, Clone, Default)]
+pub struct Filter {
+    /// Terms that must all match
+    pub terms: Vec<Term>,
+}
+
+impl Filter {
+    /// Parse a query string
+    ///
+    /// Unknown keys and malformed values are treated as plain text.
+    pub fn parse(query: &str) -> Self {
+        let terms = query.split_whitespace().map(parse_term).collect();
+        Self { terms }
+    }
+
+    /// Check whether the filter has no terms
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Match a session, returning the score and highlighted name characters
+    pub fn matches(&self, session: &Session, matcher: &SkimMatcherV2) -> Option<FilterMatch> {
+        let mut result = FilterMatch::default();
+
+        for term in &self.terms {
+            match term {
+                Term::Text(text) => {
+                    let (score, indices) = match_text(session, text, matcher)?;
+                    result.score += score;
+                    result.name_indices.extend(indices);
+                }
+                Term::Name(name) => contains(&session.name, name).then_some(())?,
+                Term::Host(host) => match &session.host {
+                    Some(h) => contains(h, host).then_some(())?,
+                    None => "local".starts_with(&host.to_lowercase()).then_some(())?,
+                },
+                Term::Status(status) => session
+                    .status
+                    .to_string()
+                    .starts_with(&status.to_lowercase())
+                    .then_some(())?,
+                Term::Branch(branch) => session
+                    .git_branch
+                    .as_deref()
+                    .filter(|b| contains(b, branch))
+                    .map(|_| ())?,
+                Term::Dir(dir) => session
+                    .working_dir
+                    .as_deref()
+                    .filter(|d| contains(d, dir))
+                    .map(|_| ())?,
+                Term::Window(window) => session
+                    .window_names
+                    .iter()
+                    .any(|w| contains(w, window))
+                    .then_some(())?,
+                Term::Age { older, duration } => {
+                    let age = Local::now().signed_duration_since(session.created);
+                    let ok = if *older {
+                        age > *duration
+                    } else {
+                        age < *duration
+                    };
+                    ok.then_some(())?
+                }
+            }
+        }
+
+        result.name_indices.sort_unstable();
+        result.name_indices.dedup();
+        Some(result)
+    }
+}
+
+/// Parse a single whitespace-separated token
+fn parse_term(token: &str) -> Term {
+    let text = || Term::Text(token.to_string());
+
+    let (key, value) = match token.split_once(':') {
+        Some((key, value)) if !value.is_empty() => (key, value.to_string()),
+        _ => return text(),
+    };
+
+    match key {
+        "name" => Term::Name(value),
+        "host" => Term::Host(value),
+        "status" => Term::Status(value),
+        "branch" => Term::Branch(value),
+        "dir" => Term::Dir(value),
+        "win" | "window" => Term::Window(value),
+        "age" => parse_age(&value).unwrap_or_else(text),
+        _ => text(),
+    }
+}
+
+/// Parse an age predicate such as `>2d`, `<30m` or `1w` (defaults to older than)
+fn parse_age(value: &str) -> Option<Term> {
+    let (older, rest) = if let Some(rest) = value.strip_prefix('<') {
+        (false, rest)
+    } else {
+        (true, value.strip_prefix('>').unwrap_or(value))
+    };
+
+    Some(Term::Age {
+        older,
+        duration: parse_duration(rest)?,
+    })
+}
+
+/// Parse a duration like `45s`, `30m`, `2h`, `3d` or `1w`
+pub fn parse_duration(s: &str) -> Option<Duration> {
+    let split = s.find(|c: char| !c.is_ascii_digit())?;
+    let (num, unit) = s.split_at(split);
+    let n: i64 = num.parse().ok()?;
+
+    match unit {
+        "s" => Some(Duration::seconds(n)),
+        "m" => Some(Duration::minutes(n)),
+        "h" => Some(Duration::hours(n)),
+        "d" => Some(Duration::days(n)),
+        "w" => Some(Duration::weeks(n)),
+        _ => None,
+    }
+}
+
+/// Case-insensitive substring check
+fn contains(haystack: &str, needle: &str) -> bool {
+    haystack.to_lowercase().contains(&needle.to_lowercase())
+}
+
+/// Fuzzy-match text against name, working directory, branch and window names
+///
+/// Returns the best score and, when the name matched, its matched indices.
+fn match_text(session: &Session, text: &str, matcher: &SkimMatcherV2) -> Option<(i64, Vec<usize>)> {
+    let name = matcher.fuzzy_indices(&session.name, text);
+
+    let others = session
+        .working_dir
+        .iter()
+        .chain(session.git_branch.iter())
+        .chain(session.window_names.iter())
+        .filter_map(|field| matcher.fuzzy_match(field, text))
+        .max();
+
+    match (name, others) {
+        (Some((score, indices)), Some(other)) if other > score => Some((other, indices)),
+        (Some((score, indices)), _) => Some((score, indices)),
+        (None, Some(other)) => Some((other, Vec::new())),
+        (None, None) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::screen::SessionStatus;
+
+    fn session() -> Session {
+        let mut session = Session::new(
+            "100.api-server".to_string(),
+            "api-server".to_string(),
+            100,
+            SessionStatus::Detached,
+        );
+        session.created = Local::now() - Duration::days(3);
+        session.working_dir = Some("/home/me/src/shop".to_string());
+        session.git_branch = Some("feat/login".to_string());
+        session.window_names = vec!["vim".to_string(), "logs".to_string()];
+        session
+    }
+
+    fn matches(query: &str) -> bool {
+        Filter::parse(query)
+            .matches(&session(), &SkimMatcherV2::default())
+            .is_some()
+    }
+
+    #[test]
+    fn test_parse_terms() {
+        let filter = Filter::parse("host:prod status:det branch:feat/ age:>2d win:vim foo");
+        assert_eq!(
+            filter.terms,
+            vec![
+                Term::Host("prod".to_string()),
+                Term::Status("det".to_string()),
+                Term::Branch("feat/".to_string()),
+                Term::Age {
+                    older: true,
+                    duration: Duration::days(2)
+                },
+                Term::Window("vim".to_string()),
+                Term::Text("foo".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_unknown_and_malformed_as_text() {
+        let filter = Filter::parse("foo:bar age:soon host:");
+        assert_eq!(
+            filter.terms,
+            vec![
+                Term::Text("foo:bar".to_string()),
+                Term::Text("age:soon".to_string()),
+                Term::Text("host:".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("45s"), Some(Duration::seconds(45)));
+        assert_eq!(parse_duration("2h"), Some(Duration::hours(2)));
+        assert_eq!(parse_duration("1w"), Some(Duration::weeks(1)));
+        assert_eq!(parse_duration("2x"), None);
+        assert_eq!(parse_duration("d"), None);
+    }
+
+    #[test]
+    fn test_predicates() {
+        assert!(matches("host:local"));
+        assert!(!matches("host:prod"));
+        assert!(matches("status:detached"));
+        assert!(!matches("status:attached"));
+        assert!(matches("branch:feat/"));
+        assert!(matches("age:>2d"));
+        assert!(!matches("age:<1d"));
+        assert!(matches("win:log"));
+        assert!(!matches("win:htop"));
+        assert!(matches("dir:shop name:api"));
+    }
+
+    #[test]
+    fn test_text_matches_other_fields() {
+        assert!(matches("api"));
+        assert!(matches("shop"));
+        assert!(matches("login"));
+        assert!(matches("logs"));
+        assert!(!matches("zzz"));
+    }
+
+    #[test]
+    fn test_name_indices() {
+        let result = Filter::parse("api")
+            .matches(&session(), &SkimMatcherV2::default())
+            .unwrap();
+        assert_eq!(result.name_indices, vec![0, 1, 2]);
+    }
+}
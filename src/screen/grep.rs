@@ -0,0 +1,191 @@
+//! Scrollback grep
+//!
+//! Searches the scrollback of every window of many sessions at once, for
+//! `sesh grep` and the grep view.
+
+use regex::Regex;
+use tokio::task::JoinSet;
+
+use super::types::{Session, Window};
+use super::window;
+use crate::config::Settings;
+
+/// A matching line in one captured scrollback
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineHit {
+    /// Line index (0-based)
+    pub line: usize,
+    /// Byte range of the first match within the line
+    pub start: usize,
+    pub end: usize,
+    /// Lines before the match
+    pub before: Vec<String>,
+    /// Lines after the match
+    pub after: Vec<String>,
+}
+
+/// A matching line in a window of a session
+#[derive(Debug, Clone)]
+pub struct GrepHit {
+    /// Session the line was captured from
+    pub session: Session,
+    /// Window the line was captured from
+    pub window: Window,
+    /// Text of the matching line
+    pub text: String,
+    /// Where the line and match are
+    pub hit: LineHit,
+}
+
+impl GrepHit {
+    /// Location as `session:window:line`, with a 1-based line number
+    pub fn location(&self) -> String {
+        format!(
+            "{}:{}:{}",
+            self.session.display_name(),
+            self.window.number,
+            self.hit.line + 1
+        )
+    }
+}
+
+/// Result of grepping many sessions
+#[derive(Debug, Clone, Default)]
+pub struct GrepReport {
+    /// Hits ordered by session, window and line
+    pub hits: Vec<GrepHit>,
+    /// Number of windows searched
+    pub windows: usize,
+    /// Sessions or windows that could not be captured, with the reason
+    pub errors: Vec<(String, String)>,
+}
+
+/// Find the lines matching `regex`, with `context` lines around each
+pub fn search_lines(regex: &Regex, lines: &[String], context: usize) -> Vec<LineHit> {
+    lines
+        .iter()
+        .enumerate()
+        .filter_map(|(line, text)| {
+            let m = regex.find_iter(text).find(|m| m.start() != m.end())?;
+            let first = line.saturating_sub(context);
+            let last = (line + 1 + context).min(lines.len());
+            Some(LineHit {
+                line,
+                start: m.start(),
+                end: m.end(),
+                before: lines[first..line].to_vec(),
+                after: lines[line + 1..last].to_vec(),
+            })
+        })
+        .collect()
+}
+
+/// Capture every window of every session and search it
+///
+/// Sessions are captured concurrently; windows of one session in turn.
+pub async fn grep(
+    config: &Settings,
+    sessions: &[Session],
+    regex: &Regex,
+    context: usize,
+) -> GrepReport {
+    let mut tasks = JoinSet::new();
+
+    for (index, session) in sessions.iter().enumerate() {
+        if session.is_virtual() {
+            continue;
+        }
+
+        let config = config.clone();
+        let session = session.clone();
+        let regex = regex.clone();
+
+        tasks.spawn(async move {
+            let host = session.host.as_deref();
+            let mut report = GrepReport::default();
+
+            let windows = match window::list(&config, host, &session.id).await {
+                Ok(windows) if !windows.is_empty() => windows,
+                // Fall back to the default window when listing fails
+                _ => vec![Window::new(0, String::new())],
+            };
+
+            for window in windows {
+                match window::capture(&config, host, &session.id, Some(window.number)).await {
+                    Ok(preview) => {
+                        report.windows += 1;
+                        for hit in search_lines(&regex, &preview.lines, context) {
+                            report.hits.push(GrepHit {
+                                session: session.clone(),
+                                window: window.clone(),
+                                text: preview.lines[hit.line].clone(),
+                                hit,
+                            });
+                        }
+                    }
+                    Err(e) => report.errors.push((
+                        format!("{}:{}", session.display_name(), window.number),
+                        e.to_string(),
+                    )),
+                }
+            }
+
+            (index, report)
+        });
+    }
+
+    let mut reports = Vec::new();
+    while let Some(joined) = tasks.join_next().await {
+        if let Ok(result) = joined {
+            reports.push(result);
+        }
+    }
+    reports.sort_by_key(|(index, _)| *index);
+
+    let mut report = GrepReport::default();
+    for (_, part) in reports {
+        report.hits.extend(part.hits);
+        report.windows += part.windows;
+        report.errors.extend(part.errors);
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines() -> Vec<String> {
+        [
+            "start",
+            "ok",
+            "panic: boom",
+            "  at main.rs:3",
+            "",
+            "panic again",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+    }
+
+    #[test]
+    fn test_search_lines_context() {
+        let regex = Regex::new("panic").unwrap();
+        let hits = search_lines(&regex, &lines(), 1);
+
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].line, 2);
+        assert_eq!((hits[0].start, hits[0].end), (0, 5));
+        assert_eq!(hits[0].before, vec!["ok".to_string()]);
+        assert_eq!(hits[0].after, vec!["  at main.rs:3".to_string()]);
+        assert_eq!(hits[1].line, 5);
+        assert!(hits[1].after.is_empty());
+    }
+
+    #[test]
+    fn test_search_lines_skips_empty_matches() {
+        let regex = Regex::new("x*").unwrap();
+        assert!(search_lines(&regex, &lines(), 0).is_empty());
+    }
+}
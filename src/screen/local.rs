@@ -37,6 +37,126 @@ pub async fn list_sessions() -> Result<Vec<Session>> {
     parser::parse_session_list(&combined, None)
 }
 
+/// Get the working directory of a session's screen process
+///
+/// Reads `/proc/<pid>/cwd`, so this only works on Linux.
+pub fn session_working_dir(pid: u32) -> Option<String> {
+    std::fs::read_link(format!("/proc/{}/cwd", pid))
+        .ok()
+        .map(|path| path.to_string_lossy().into_owned())
+}
+
+/// Shells whose foreground job is the window's command
+const SHELLS: &[&str] = &["bash", "zsh", "fish", "sh", "dash", "ksh", "tcsh", "csh"];
+
+/// What runs in a window, read from `/proc`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WindowProcess {
+    /// Window number
+    pub number: usize,
+    /// Working directory of the window's process
+    pub working_dir: Option<String>,
+    /// Foreground command (None for an idle shell)
+    pub command: Option<String>,
+}
+
+/// Working directory and foreground command of each window of a session
+///
+/// Windows are the children of the screen process, told apart by the
+/// `WINDOW` variable screen sets in their environment. Reads `/proc`, so
+/// this only works on Linux.
+pub fn window_processes(pid: u32) -> Vec<WindowProcess> {
+    let Ok(entries) = std::fs::read_dir("/proc") else {
+        return Vec::new();
+    };
+
+    let mut windows = Vec::new();
+    for entry in entries.flatten() {
+        let Some(child) = entry
+            .file_name()
+            .to_str()
+            .and_then(|s| s.parse::<u32>().ok())
+        else {
+            continue;
+        };
+        let Some(stat) = read_proc_stat(child).filter(|stat| stat.ppid == pid) else {
+            continue;
+        };
+        let Some(number) = proc_environ_var(child, "WINDOW").and_then(|w| w.parse().ok()) else {
+            continue;
+        };
+
+        // A shell's foreground job is the command; anything else was started
+        // as the window's program
+        let command = if SHELLS.contains(&stat.comm.trim_start_matches('-')) {
+            u32::try_from(stat.tpgid)
+                .ok()
+                .filter(|&tpgid| tpgid != child)
+                .and_then(read_proc_cmdline)
+        } else {
+            read_proc_cmdline(child)
+        };
+
+        windows.push(WindowProcess {
+            number,
+            working_dir: session_working_dir(child),
+            command,
+        });
+    }
+
+    windows.sort_by_key(|w| w.number);
+    windows
+}
+
+/// Read and parse `/proc/<pid>/stat`
+fn read_proc_stat(pid: u32) -> Option<parser::ProcStat> {
+    let content = std::fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+    parser::parse_proc_stat(&content)
+}
+
+/// Read a process's command line
+fn read_proc_cmdline(pid: u32) -> Option<String> {
+    let content = std::fs::read(format!("/proc/{}/cmdline", pid)).ok()?;
+    parser::parse_proc_cmdline(&content)
+}
+
+/// Read a variable from a process's environment
+fn proc_environ_var(pid: u32, name: &str) -> Option<String> {
+    let content = std::fs::read(format!("/proc/{}/environ", pid)).ok()?;
+    let prefix = format!("{}=", name);
+    content
+        .split(|&b| b == 0)
+        .find_map(|var| var.strip_prefix(prefix.as_bytes()))
+        .map(|value| String::from_utf8_lossy(value).into_owned())
+}
+
+/// Fill in working directory, windows and git info for local sessions
+///
+/// Git branch and status are only read when `git_status` is enabled.
+pub async fn annotate_sessions(sessions: &mut [Session], git_status: bool) {
+    for session in sessions.iter_mut().filter(|s| s.is_local()) {
+        session.working_dir = session_working_dir(session.pid);
+
+        if let Ok(windows) = list_windows(&session.id).await {
+            session.window_count = windows.len();
+            session.window_names = windows.into_iter().map(|w| w.name).collect();
+        }
+
+        if let Some(ref dir) = session.working_dir {
+            let dir = std::path::Path::new(dir);
+            session.repo_root = crate::integrations::git::get_repo_root(dir)
+                .map(|root| root.trim_end_matches('/').to_string());
+
+            if git_status {
+                if let Some(status) = crate::integrations::git::get_git_status(dir) {
+                    session.git_branch = Some(status.branch);
+                    session.git_clean = Some(status.is_clean);
+                }
+            }
+        }
+    }
+}
+
 /// Create a new screen session
 pub async fn create_session(name: &str, dir: Option<&str>, shell: Option<&str>) -> Result<()> {
     let mut cmd = Command::new("screen");
@@ -337,49 +457,42 @@ pub async fn create_window(session: &str, name: Option<&str>) -> Result<()> {
 
 /// Rename a window
 pub async fn rename_window(session: &str, window: usize, name: &str) -> Result<()> {
-    // First select the window
-    Command::new("screen")
-        .args(["-S", session, "-X", "select", &window.to_string()])
-        .output()
-        .await?;
-
-    // Then rename it
-    let output = Command::new("screen")
-        .args(["-S", session, "-X", "title", name])
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .output()
-        .await
-        .context("Failed to rename window")?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        bail!("Failed to rename window: {}", stderr);
-    }
-
-    Ok(())
+    window_command(session, window, &["title", name], "rename window").await
 }
 
 /// Kill a window in a session
 pub async fn kill_window(session: &str, window: usize) -> Result<()> {
-    // Select the window first
-    Command::new("screen")
-        .args(["-S", session, "-X", "select", &window.to_string()])
-        .output()
-        .await?;
+    window_command(session, window, &["kill"], "kill window").await
+}
+
+/// Renumber a window (swaps with the window already using that number)
+pub async fn move_window(session: &str, window: usize, number: usize) -> Result<()> {
+    window_command(
+        session,
+        window,
+        &["number", &number.to_string()],
+        "move window",
+    )
+    .await
+}
+
+/// Run a screen command against one window without switching to it
+async fn window_command(session: &str, window: usize, command: &[&str], what: &str) -> Result<()> {
+    let window = window.to_string();
+    let mut args = vec!["-S", session, "-p", &window, "-X"];
+    args.extend_from_slice(command);
 
-    // Kill it
     let output = Command::new("screen")
-        .args(["-S", session, "-X", "kill"])
+        .args(&args)
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
         .output()
         .await
-        .context("Failed to kill window")?;
+        .with_context(|| format!("Failed to {}", what))?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        bail!("Failed to kill window: {}", stderr);
+        bail!("Failed to {}: {}", what, stderr);
     }
 
     Ok(())
@@ -405,18 +518,20 @@ pub async fn select_window(session: &str, window: usize) -> Result<()> {
 
 /// Get preview of terminal content
 pub async fn get_preview(session: &str, window: Option<usize>) -> Result<Preview> {
+    // Use -p to specify which window to capture (default to window 0)
+    let window_num = window.unwrap_or(0).to_string();
+
+    // One file per window so concurrent captures don't clash
     let temp_file = format!(
-        "/tmp/sesh-preview-{}-{}",
+        "/tmp/sesh-preview-{}-{}-{}",
         std::process::id(),
-        session.replace('.', "_")
+        session.replace('.', "_"),
+        window_num
     );
 
     // Remove any existing temp file first
     let _ = tokio::fs::remove_file(&temp_file).await;
 
-    // Use -p to specify which window to capture (default to window 0)
-    let window_num = window.unwrap_or(0).to_string();
-
     // Capture terminal content with scrollback using -p to specify window
     let _output = Command::new("screen")
         .args([
@@ -459,13 +574,17 @@ pub async fn get_preview(session: &str, window: Option<usize>) -> Result<Preview
         total_lines: lines.len(),
         scroll_offset: 0,
         updating: false,
+        changed: Vec::new(),
     })
 }
 
 /// Send a command to a session
-pub async fn send_command(session: &str, command: &str) -> Result<()> {
+///
+/// The command is split into arguments as screen's own command line would
+/// and applies to the given window, or the current window when None.
+pub async fn send_command(session: &str, window: Option<usize>, command: &str) -> Result<()> {
     let output = Command::new("screen")
-        .args(["-S", session, "-X", command])
+        .args(command_args(session, window, "-X", command))
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
         .output()
@@ -480,10 +599,54 @@ pub async fn send_command(session: &str, command: &str) -> Result<()> {
     Ok(())
 }
 
+/// Run a query command (`screen -Q`) and return screen's reply
+pub async fn query_command(
+    session: &str,
+    window: Option<usize>,
+    command: &str,
+) -> Result<Option<String>> {
+    let output = Command::new("screen")
+        .args(command_args(session, window, "-Q", command))
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .await
+        .context("Failed to query session")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("Failed to query session: {}", stderr);
+    }
+
+    Ok(parser::parse_query_response(&String::from_utf8_lossy(
+        &output.stdout,
+    )))
+}
+
+/// Arguments for running a screen command in a session
+fn command_args(session: &str, window: Option<usize>, flag: &str, command: &str) -> Vec<String> {
+    let mut args = vec!["-S".to_string(), session.to_string()];
+    if let Some(window) = window {
+        args.extend(["-p".to_string(), window.to_string()]);
+    }
+    args.push(flag.to_string());
+    args.extend(parser::split_command(command));
+    args
+}
+
 /// Send text input to a session (stuff command)
-pub async fn send_keys(session: &str, keys: &str) -> Result<()> {
+///
+/// Goes to the given window, or to the session's current window when None.
+/// `keys` is passed to `stuff` as is, so `^` and `\` keep their meaning.
+pub async fn send_keys(session: &str, window: Option<usize>, keys: &str) -> Result<()> {
+    let mut args = vec!["-S".to_string(), session.to_string()];
+    if let Some(window) = window {
+        args.extend(["-p".to_string(), window.to_string()]);
+    }
+    args.extend(["-X".to_string(), "stuff".to_string(), keys.to_string()]);
+
     let output = Command::new("screen")
-        .args(["-S", session, "-X", "stuff", keys])
+        .args(&args)
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
         .output()
@@ -498,6 +661,59 @@ pub async fn send_keys(session: &str, keys: &str) -> Result<()> {
     Ok(())
 }
 
+/// Put text into a session's paste buffer
+///
+/// The text goes through a file and `readbuf`, so screen does not
+/// interpret any of its characters.
+pub async fn set_paste_buffer(session: &str, text: &str) -> Result<()> {
+    use std::io::Write;
+
+    // Created exclusively and readable only by the user: it holds whatever
+    // was copied until screen has read it
+    let mut temp_file = tempfile::Builder::new()
+        .prefix("sesh-buffer-")
+        .tempfile()
+        .context("Failed to create paste buffer file")?;
+    temp_file
+        .write_all(text.as_bytes())
+        .and_then(|()| temp_file.flush())
+        .context("Failed to write paste buffer file")?;
+
+    let output = Command::new("screen")
+        .args(["-S", session, "-X", "readbuf"])
+        .arg(temp_file.path())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .await;
+
+    // -X only queues readbuf. Queries are answered in order, so once this
+    // one returns screen has read the file and it can be removed.
+    let synced = match output {
+        Ok(ref output) if output.status.success() => {
+            query_command(session, None, "version").await.is_ok()
+        }
+        _ => true,
+    };
+    if synced {
+        drop(temp_file);
+    } else {
+        // No reply (screen too old for -Q): remove it once screen has had time
+        tokio::spawn(async move {
+            tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+            drop(temp_file);
+        });
+    }
+
+    let output = output.context("Failed to set paste buffer")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("Failed to set paste buffer: {}", stderr);
+    }
+
+    Ok(())
+}
+
 /// Check if screen is installed and available
 pub async fn check_screen_available() -> Result<bool> {
     let output = Command::new("which").arg("screen").output().await;
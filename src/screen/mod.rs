@@ -3,9 +3,24 @@
 //! Provides functionality for interacting with GNU Screen sessions,
 //! both locally and over SSH.
 
+pub mod broadcast;
+pub mod bulk;
+pub mod capture;
+pub mod console;
+pub mod dashboard;
+pub mod filter;
+pub mod grep;
 pub mod local;
 pub mod parser;
 pub mod remote;
+pub mod run;
+pub mod scrollback;
+pub mod selection;
+pub mod send;
 pub mod types;
+pub mod window;
 
-pub use types::{Preview, Session, SessionStatus, Window, WindowActivity};
+pub use filter::Filter;
+pub use types::{
+    GroupBy, Preview, Session, SessionGroup, SessionStatus, SortKey, Window, WindowActivity,
+};
@@ -172,6 +172,92 @@ pub fn parse_query_response(output: &str) -> Option<String> {
     }
 }
 
+/// Split a screen command line into arguments
+///
+/// Words are separated by whitespace; single or double quotes group words
+/// and are removed, as screen does for `-X` and its own command line.
+pub fn split_command(command: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut in_word = false;
+    let mut quote: Option<char> = None;
+
+    for c in command.chars() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), c) => current.push(c),
+            (None, '"' | '\'') => {
+                quote = Some(c);
+                in_word = true;
+            }
+            (None, c) if c.is_whitespace() => {
+                if in_word {
+                    args.push(std::mem::take(&mut current));
+                    in_word = false;
+                }
+            }
+            (None, c) => {
+                current.push(c);
+                in_word = true;
+            }
+        }
+    }
+    if in_word {
+        args.push(current);
+    }
+
+    args
+}
+
+/// Fields of `/proc/<pid>/stat` used to find a window's processes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcStat {
+    /// Executable name
+    pub comm: String,
+    /// Parent process ID
+    pub ppid: u32,
+    /// Foreground process group of the controlling terminal (-1 if none)
+    pub tpgid: i32,
+}
+
+/// Parse the contents of `/proc/<pid>/stat`
+///
+/// The name is in parentheses and may itself contain spaces or parentheses,
+/// so fields are counted from the last `)`.
+pub fn parse_proc_stat(content: &str) -> Option<ProcStat> {
+    let open = content.find('(')?;
+    let close = content.rfind(')')?;
+    let comm = content.get(open + 1..close)?.to_string();
+    let fields: Vec<&str> = content[close + 1..].split_whitespace().collect();
+
+    Some(ProcStat {
+        comm,
+        ppid: fields.get(1)?.parse().ok()?,
+        tpgid: fields.get(5)?.parse().ok()?,
+    })
+}
+
+/// Turn `/proc/<pid>/cmdline` into a shell command line
+///
+/// Arguments are separated by NUL bytes; ones with spaces or quotes are
+/// single-quoted. Returns None for kernel threads and zombies (empty).
+pub fn parse_proc_cmdline(content: &[u8]) -> Option<String> {
+    let args: Vec<String> = content
+        .split(|&b| b == 0)
+        .filter(|arg| !arg.is_empty())
+        .map(|arg| {
+            let arg = String::from_utf8_lossy(arg);
+            if arg.contains(|c: char| c.is_whitespace() || "'\"$`\\".contains(c)) {
+                format!("'{}'", arg.replace('\'', "'\\''"))
+            } else {
+                arg.into_owned()
+            }
+        })
+        .collect();
+
+    (!args.is_empty()).then(|| args.join(" "))
+}
+
 /// Check if screen -ls output indicates no sessions
 pub fn is_no_sessions(output: &str) -> bool {
     output.contains("No Sockets found") || output.contains("No sessions")
@@ -398,4 +484,41 @@ mod tests {
         ];
         assert_yaml_snapshot!(activities);
     }
+
+    #[test]
+    fn test_split_command() {
+        assert_eq!(
+            split_command("defscrollback 10000"),
+            ["defscrollback", "10000"]
+        );
+        assert_eq!(
+            split_command("  logfile '/tmp/my log'  "),
+            ["logfile", "/tmp/my log"]
+        );
+        assert_eq!(split_command("title \"\""), ["title", ""]);
+        assert!(split_command("   ").is_empty());
+    }
+
+    #[test]
+    fn test_parse_proc_stat() {
+        let stat = "4242 (tmux: (a) b) S 4100 4242 4242 34817 4300 4194304 0 0";
+        assert_eq!(
+            parse_proc_stat(stat),
+            Some(ProcStat {
+                comm: "tmux: (a) b".to_string(),
+                ppid: 4100,
+                tpgid: 4300,
+            })
+        );
+        assert_eq!(parse_proc_stat("garbage"), None);
+    }
+
+    #[test]
+    fn test_parse_proc_cmdline() {
+        assert_eq!(
+            parse_proc_cmdline(b"tail\0-f\0/var/log/my app.log\0"),
+            Some("tail -f '/var/log/my app.log'".to_string())
+        );
+        assert_eq!(parse_proc_cmdline(b""), None);
+    }
 }
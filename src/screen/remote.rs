@@ -149,6 +149,29 @@ pub async fn kill_session(config: &Settings, host_name: &str, session: &str) ->
     Ok(())
 }
 
+/// Rename a session on a remote host
+pub async fn rename_session(
+    config: &Settings,
+    host_name: &str,
+    session: &str,
+    new_name: &str,
+) -> Result<()> {
+    let session = shell_quote(session);
+    let new_name = shell_quote(new_name);
+    let output = run_ssh_command(
+        config,
+        host_name,
+        &["screen", "-S", &session, "-X", "sessionname", &new_name],
+    )
+    .await?;
+
+    if output.to_lowercase().contains("error") {
+        bail!("Failed to rename remote session: {}", output);
+    }
+
+    Ok(())
+}
+
 /// List windows in a remote session
 pub async fn list_windows(
     config: &Settings,
@@ -165,34 +188,147 @@ pub async fn list_windows(
     parser::parse_window_list(&output)
 }
 
-/// Get preview content from a remote session
-pub async fn get_preview(
+/// Create a window in a remote session
+pub async fn create_window(
     config: &Settings,
     host_name: &str,
     session: &str,
-    window: Option<usize>,
-) -> Result<super::types::Preview> {
-    let temp_file = format!("/tmp/sesh-preview-{}", std::process::id());
+    name: Option<&str>,
+) -> Result<()> {
+    let session = shell_quote(session);
+    let mut args = vec!["screen", "-S", &session, "-X", "screen"];
+    let quoted = name.map(shell_quote);
+    if let Some(ref name) = quoted {
+        args.push("-t");
+        args.push(name);
+    }
 
-    // Build the command sequence
-    let mut commands = Vec::new();
+    let output = run_ssh_command(config, host_name, &args).await?;
+    check_window_output(&output, "create window")
+}
+
+/// Rename a window in a remote session
+pub async fn rename_window(
+    config: &Settings,
+    host_name: &str,
+    session: &str,
+    window: usize,
+    name: &str,
+) -> Result<()> {
+    let name = shell_quote(name);
+    window_command(
+        config,
+        host_name,
+        session,
+        window,
+        &["title", &name],
+        "rename window",
+    )
+    .await
+}
 
-    // Use -p to specify window (default to 0)
-    let win_num = window.unwrap_or(0);
+/// Kill a window in a remote session
+pub async fn kill_window(
+    config: &Settings,
+    host_name: &str,
+    session: &str,
+    window: usize,
+) -> Result<()> {
+    window_command(config, host_name, session, window, &["kill"], "kill window").await
+}
 
-    // Capture content using -p to specify which window
-    commands.push(format!(
-        "screen -p {} -S {} -X hardcopy -h {}",
-        win_num, session, temp_file
-    ));
+/// Renumber a window in a remote session
+pub async fn move_window(
+    config: &Settings,
+    host_name: &str,
+    session: &str,
+    window: usize,
+    number: usize,
+) -> Result<()> {
+    let number = number.to_string();
+    window_command(
+        config,
+        host_name,
+        session,
+        window,
+        &["number", &number],
+        "move window",
+    )
+    .await
+}
 
-    // Wait a bit then cat and cleanup
-    commands.push("sleep 0.1".to_string());
-    commands.push(format!("cat {} 2>/dev/null", temp_file));
-    commands.push(format!("rm -f {}", temp_file));
+/// Select a window in a remote session
+pub async fn select_window(
+    config: &Settings,
+    host_name: &str,
+    session: &str,
+    window: usize,
+) -> Result<()> {
+    let session = shell_quote(session);
+    let output = run_ssh_command(
+        config,
+        host_name,
+        &[
+            "screen",
+            "-S",
+            &session,
+            "-X",
+            "select",
+            &window.to_string(),
+        ],
+    )
+    .await?;
+    check_window_output(&output, "select window")
+}
 
-    let full_cmd = commands.join(" && ");
-    let output = run_ssh_command(config, host_name, &["sh", "-c", &full_cmd]).await?;
+/// Run a screen command against one window of a remote session
+async fn window_command(
+    config: &Settings,
+    host_name: &str,
+    session: &str,
+    window: usize,
+    command: &[&str],
+    what: &str,
+) -> Result<()> {
+    let session = shell_quote(session);
+    let window = window.to_string();
+    let mut args = vec!["screen", "-S", &session, "-p", &window, "-X"];
+    args.extend_from_slice(command);
+
+    let output = run_ssh_command(config, host_name, &args).await?;
+    check_window_output(&output, what)
+}
+
+/// Fail if screen reported an error for a window command
+fn check_window_output(output: &str, what: &str) -> Result<()> {
+    if output.to_lowercase().contains("error") || output.contains("No screen session") {
+        bail!("Failed to {}: {}", what, output.trim());
+    }
+
+    Ok(())
+}
+
+/// Quote an argument for a POSIX shell (here, the remote one)
+pub fn shell_quote(arg: &str) -> String {
+    format!("'{}'", arg.replace('\'', "'\\''"))
+}
+
+/// Get preview content from a remote session
+pub async fn get_preview(
+    config: &Settings,
+    host_name: &str,
+    session: &str,
+    window: Option<usize>,
+) -> Result<super::types::Preview> {
+    let argv = preview_command(session, window);
+    let argv: Vec<&str> = argv.iter().map(String::as_str).collect();
+    let output = run_ssh_command(config, host_name, &argv).await?;
+
+    // Only screen's complaint comes back when the session is gone; don't
+    // show it as the window's content
+    if output.starts_with("No screen session") {
+        bail!("Failed to capture preview: {}", output.trim());
+    }
 
     let lines = parser::parse_hardcopy(&output);
 
@@ -201,18 +337,39 @@ pub async fn get_preview(
         total_lines: lines.len(),
         scroll_offset: 0,
         updating: false,
+        changed: Vec::new(),
     })
 }
 
+/// Remote command capturing a window's scrollback (window 0 when None)
+///
+/// One script, passed as a single argument: ssh joins its arguments with
+/// spaces, so anything else would be split again by the remote shell. The
+/// query after `hardcopy` is answered once screen has written the file.
+fn preview_command(session: &str, window: Option<usize>) -> Vec<String> {
+    let session = shell_quote(session);
+    let script = format!(
+        "f=$(mktemp) && screen -p {window} -S {session} -X hardcopy -h \"$f\" && \
+         {{ screen -S {session} -Q version >/dev/null 2>&1 || sleep 0.1; cat \"$f\"; }}; \
+         rm -f \"$f\"",
+        window = window.unwrap_or(0),
+    );
+    vec![script]
+}
+
 /// Send a command to a remote session
+///
+/// Applies to the given window, or the current window when None.
 pub async fn send_command(
     config: &Settings,
     host_name: &str,
     session: &str,
+    window: Option<usize>,
     command: &str,
 ) -> Result<()> {
-    let output =
-        run_ssh_command(config, host_name, &["screen", "-S", session, "-X", command]).await?;
+    let args = command_args(session, window, "-X", command);
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let output = run_ssh_command(config, host_name, &args).await?;
 
     if output.to_lowercase().contains("error") {
         bail!("Failed to send command to remote session: {}", output);
@@ -221,6 +378,86 @@ pub async fn send_command(
     Ok(())
 }
 
+/// Run a query command (`screen -Q`) in a remote session and return the reply
+pub async fn query_command(
+    config: &Settings,
+    host_name: &str,
+    session: &str,
+    window: Option<usize>,
+    command: &str,
+) -> Result<Option<String>> {
+    let args = command_args(session, window, "-Q", command);
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let output = run_ssh_command(config, host_name, &args).await?;
+    check_window_output(&output, "query session")?;
+
+    Ok(parser::parse_query_response(&output))
+}
+
+/// Shell-quoted arguments for running a screen command in a remote session
+fn command_args(session: &str, window: Option<usize>, flag: &str, command: &str) -> Vec<String> {
+    let mut args = vec!["screen".to_string(), "-S".to_string(), shell_quote(session)];
+    if let Some(window) = window {
+        args.extend(["-p".to_string(), window.to_string()]);
+    }
+    args.push(flag.to_string());
+    args.extend(
+        parser::split_command(command)
+            .iter()
+            .map(|arg| shell_quote(arg)),
+    );
+    args
+}
+
+/// Send text input to a remote session (stuff command)
+///
+/// Goes to the given window, or to the session's current window when None.
+pub async fn send_keys(
+    config: &Settings,
+    host_name: &str,
+    session: &str,
+    window: Option<usize>,
+    keys: &str,
+) -> Result<()> {
+    let session = shell_quote(session);
+    let keys = shell_quote(keys);
+    let window = window.map(|w| w.to_string());
+
+    let mut args = vec!["screen", "-S", &session];
+    if let Some(ref window) = window {
+        args.extend(["-p", window]);
+    }
+    args.extend(["-X", "stuff", &keys]);
+
+    let output = run_ssh_command(config, host_name, &args).await?;
+    check_window_output(&output, "send keys")
+}
+
+/// Put text into a remote session's paste buffer
+pub async fn set_paste_buffer(
+    config: &Settings,
+    host_name: &str,
+    session: &str,
+    text: &str,
+) -> Result<()> {
+    // -X only queues readbuf; the query is answered after it has run, so
+    // the file is no longer needed once it returns (or after a pause if it
+    // fails)
+    let session = shell_quote(session);
+    let script = format!(
+        "f=$(mktemp) && printf %s {text} > \"$f\" && screen -S {session} -X readbuf \"$f\"; \
+         screen -S {session} -Q version >/dev/null 2>&1 || sleep 1; rm -f \"$f\"",
+        text = shell_quote(text),
+    );
+    let output = run_ssh_command(config, host_name, &[&script]).await?;
+
+    if output.to_lowercase().contains("error") || output.contains("No screen session") {
+        bail!("Failed to set paste buffer: {}", output.trim());
+    }
+
+    Ok(())
+}
+
 /// Check if a host is reachable via SSH
 pub async fn check_host_reachable(config: &Settings, host_name: &str) -> Result<bool> {
     let result = run_ssh_command(config, host_name, &["echo", "ok"]).await;
@@ -236,4 +473,21 @@ pub async fn get_screen_version(config: &Settings, host_name: &str) -> Result<St
 #[cfg(test)]
 mod tests {
     // Remote tests require actual SSH setup, so we skip them in unit tests
+    use super::*;
+
+    #[test]
+    fn test_shell_quote() {
+        assert_eq!(shell_quote("my logs"), "'my logs'");
+        assert_eq!(shell_quote("it's"), "'it'\\''s'");
+    }
+
+    #[test]
+    fn test_preview_command() {
+        let argv = preview_command("1234.it's here", Some(2));
+        assert_eq!(argv.len(), 1);
+        assert!(argv[0].contains("screen -p 2 -S '1234.it'\\''s here' -X hardcopy -h \"$f\""));
+        assert!(argv[0].contains("cat \"$f\""));
+
+        assert!(preview_command("api", None)[0].contains("-p 0 -S 'api'"));
+    }
 }
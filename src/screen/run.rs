@@ -0,0 +1,284 @@
+//! Running commands in windows
+//!
+//! Types a command into a window and polls its scrollback until the output
+//! matches an expected pattern, a shell prompt reappears, or a sentinel
+//! echoed after the command reports its exit status.
+
+use anyhow::Result;
+use regex::Regex;
+use std::time::{Duration, Instant};
+
+use super::{send, window};
+use crate::config::Settings;
+
+/// Prefix of the line echoed when the command finishes
+const SENTINEL_PREFIX: &str = "__sesh_done_";
+
+/// Exit status reported on timeout (as with timeout(1))
+pub const TIMEOUT_STATUS: i32 = 124;
+
+/// What ends the wait for a command
+#[derive(Debug, Clone, Default)]
+pub struct WaitFor {
+    /// Output that means success
+    pub expect: Option<Regex>,
+    /// Output that means failure
+    pub fail: Option<Regex>,
+    /// Shell prompt that means the command finished
+    pub prompt: Option<Regex>,
+    /// Token of the sentinel echoed after the command (None to not add one)
+    pub sentinel: Option<String>,
+    /// Give up after this long (None to wait forever)
+    pub timeout: Option<Duration>,
+}
+
+/// How a command run ended
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// The expect pattern matched this line
+    Expected(String),
+    /// The fail pattern matched this line
+    Failed(String),
+    /// The sentinel reported this exit status
+    Exited(i32),
+    /// The shell prompt came back
+    Prompt,
+    /// Nothing matched in time
+    TimedOut,
+}
+
+impl Outcome {
+    /// Exit status for `sesh run`
+    pub fn status(&self) -> i32 {
+        match self {
+            Outcome::Expected(_) | Outcome::Prompt => 0,
+            Outcome::Failed(_) => 1,
+            Outcome::Exited(status) => *status,
+            Outcome::TimedOut => TIMEOUT_STATUS,
+        }
+    }
+
+    /// One-line description
+    pub fn describe(&self) -> String {
+        match self {
+            Outcome::Expected(line) => format!("matched expect: {}", line.trim()),
+            Outcome::Failed(line) => format!("matched fail: {}", line.trim()),
+            Outcome::Exited(status) => format!("exited with status {}", status),
+            Outcome::Prompt => "prompt returned".to_string(),
+            Outcome::TimedOut => "timed out".to_string(),
+        }
+    }
+}
+
+/// Result of running a command
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunResult {
+    /// How the run ended
+    pub outcome: Outcome,
+    /// Output that appeared after the command line
+    pub output: Vec<String>,
+}
+
+/// Token unique to this run, for the sentinel
+pub fn sentinel_token() -> String {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.subsec_nanos());
+    format!("{}_{}", std::process::id(), nanos)
+}
+
+/// Command line with the sentinel appended
+///
+/// The marker is split by quotes so the echoed command line itself never
+/// matches it.
+pub fn with_sentinel(command: &str, token: &str) -> String {
+    format!("{}; echo \"{}\"\"{}:$?\"", command, SENTINEL_PREFIX, token)
+}
+
+/// Index where output new since `before` starts in `after`
+///
+/// The earlier capture may have lost lines off the top of the scrollback,
+/// and its last line (the prompt) now also holds the typed command, so the
+/// new output starts after that line. When the captures can't be lined up
+/// (a redrawn or clock prompt), no lines are assumed to have been dropped
+/// rather than treating the whole scrollback as new.
+pub fn new_output_start(before: &[String], after: &[String]) -> usize {
+    let before = trim_trailing_blank(before);
+    if before.is_empty() {
+        return 0;
+    }
+
+    for dropped in 0..before.len() {
+        let kept = &before[dropped..];
+        if kept.len() > after.len() {
+            continue;
+        }
+        let (last, rest) = kept.split_last().expect("kept is not empty");
+        if rest.iter().zip(after).all(|(a, b)| a == b)
+            && after[rest.len()].starts_with(last.as_str())
+        {
+            return kept.len();
+        }
+    }
+
+    before.len().min(after.len())
+}
+
+/// Check new output for something that ends the wait
+///
+/// Returns the outcome and how many lines belong to the command's output.
+pub fn check(wait: &WaitFor, lines: &[String]) -> Option<(Outcome, usize)> {
+    if let Some(ref fail) = wait.fail {
+        if let Some(line) = lines.iter().find(|line| fail.is_match(line)) {
+            return Some((Outcome::Failed(line.clone()), output_len(wait, lines)));
+        }
+    }
+    if let Some(ref expect) = wait.expect {
+        if let Some(line) = lines.iter().find(|line| expect.is_match(line)) {
+            return Some((Outcome::Expected(line.clone()), output_len(wait, lines)));
+        }
+    }
+    if let Some((index, status)) = find_sentinel(wait, lines) {
+        return Some((Outcome::Exited(status), index));
+    }
+    if let Some(ref prompt) = wait.prompt {
+        let lines = trim_trailing_blank(lines);
+        if lines.last().is_some_and(|line| prompt.is_match(line)) {
+            return Some((Outcome::Prompt, lines.len() - 1));
+        }
+    }
+    None
+}
+
+/// Line index and exit status of the sentinel, if it was echoed
+fn find_sentinel(wait: &WaitFor, lines: &[String]) -> Option<(usize, i32)> {
+    let marker = format!("{}{}:", SENTINEL_PREFIX, wait.sentinel.as_ref()?);
+    lines.iter().enumerate().find_map(|(index, line)| {
+        let (_, rest) = line.split_once(&marker)?;
+        let digits: String = rest.chars().take_while(char::is_ascii_digit).collect();
+        Some((index, digits.parse().ok()?))
+    })
+}
+
+/// Number of output lines before the sentinel (or all of them)
+fn output_len(wait: &WaitFor, lines: &[String]) -> usize {
+    find_sentinel(wait, lines).map_or(lines.len(), |(index, _)| index)
+}
+
+/// Lines without the blank ones at the end
+fn trim_trailing_blank(lines: &[String]) -> &[String] {
+    let end = lines
+        .iter()
+        .rposition(|line| !line.trim().is_empty())
+        .map_or(0, |i| i + 1);
+    &lines[..end]
+}
+
+/// Type a command into a window and wait for it to finish
+pub async fn run(
+    config: &Settings,
+    host: Option<&str>,
+    session: &str,
+    window: usize,
+    command: &str,
+    wait: &WaitFor,
+    interval: Duration,
+) -> Result<RunResult> {
+    let before = window::capture(config, host, session, Some(window)).await?;
+
+    let line = match wait.sentinel {
+        Some(ref token) => with_sentinel(command, token),
+        None => command.to_string(),
+    };
+    send::send(config, host, session, Some(window), &line, true).await?;
+
+    let started = Instant::now();
+    loop {
+        tokio::time::sleep(interval).await;
+
+        let after = window::capture(config, host, session, Some(window)).await?;
+        let new = &after.lines[new_output_start(&before.lines, &after.lines)..];
+
+        if let Some((outcome, len)) = check(wait, new) {
+            return Ok(RunResult {
+                outcome,
+                output: trim_trailing_blank(&new[..len]).to_vec(),
+            });
+        }
+
+        if wait
+            .timeout
+            .is_some_and(|timeout| started.elapsed() >= timeout)
+        {
+            return Ok(RunResult {
+                outcome: Outcome::TimedOut,
+                output: trim_trailing_blank(new).to_vec(),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &[&str]) -> Vec<String> {
+        text.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_new_output_start() {
+        let before = lines(&["old", "$ ", ""]);
+        let after = lines(&["old", "$ make", "building", "$ "]);
+        assert_eq!(new_output_start(&before, &after), 2);
+
+        // The first line scrolled out of the history
+        let before = lines(&["gone", "old", "$ "]);
+        let after = lines(&["old", "$ make", "ok"]);
+        assert_eq!(new_output_start(&before, &after), 2);
+
+        assert_eq!(new_output_start(&[], &after), 0);
+
+        // The prompt was redrawn with a new time, so nothing lines up; the
+        // old PASS must not count as new output
+        let before = lines(&["PASS", "[12:00] $ ", ""]);
+        let after = lines(&["PASS", "[12:01] $ make test", "running"]);
+        assert_eq!(new_output_start(&before, &after), 2);
+    }
+
+    #[test]
+    fn test_check_sentinel_and_patterns() {
+        let token = "42_7";
+        let command = with_sentinel("make test", token);
+        let output = lines(&[
+            "running",
+            "PASS",
+            &format!("{}{}:3", SENTINEL_PREFIX, token),
+            "$ ",
+        ]);
+
+        let mut wait = WaitFor {
+            sentinel: Some(token.to_string()),
+            ..Default::default()
+        };
+        // The echoed command line doesn't count as the sentinel
+        assert_eq!(check(&wait, &[command]), None);
+        assert_eq!(check(&wait, &output), Some((Outcome::Exited(3), 2)));
+
+        wait.expect = Some(Regex::new("PASS|OK").unwrap());
+        assert_eq!(
+            check(&wait, &output),
+            Some((Outcome::Expected("PASS".to_string()), 2))
+        );
+
+        wait.fail = Some(Regex::new("^running").unwrap());
+        assert_eq!(check(&wait, &output).unwrap().0.status(), 1);
+
+        let prompt = WaitFor {
+            prompt: Some(Regex::new(r"\$ $").unwrap()),
+            ..Default::default()
+        };
+        assert_eq!(check(&prompt, &output[..2]), None);
+        assert_eq!(check(&prompt, &output), Some((Outcome::Prompt, 3)));
+    }
+}
@@ -0,0 +1,200 @@
+//! Scrollback search
+//!
+//! Incremental regex search over captured preview lines.
+
+use regex::{Regex, RegexBuilder};
+
+/// One match in the scrollback
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchMatch {
+    /// Line index (0-based)
+    pub line: usize,
+    /// Byte offset of the match start within the line
+    pub start: usize,
+    /// Byte offset of the match end within the line
+    pub end: usize,
+}
+
+/// State of a search over the preview
+#[derive(Debug, Clone, Default)]
+pub struct ScrollbackSearch {
+    /// Pattern as typed
+    pub query: String,
+    /// Matches in line order
+    pub matches: Vec<SearchMatch>,
+    /// Index into `matches` of the current match
+    pub current: Option<usize>,
+    /// Why the pattern could not be compiled
+    pub error: Option<String>,
+}
+
+impl ScrollbackSearch {
+    /// Compile the query and find every match in `lines`
+    ///
+    /// The search is case-insensitive unless the query contains an
+    /// uppercase letter. The current match is kept on the same line when
+    /// possible.
+    pub fn update(&mut self, lines: &[String]) {
+        let current_line = self.current_match().map(|m| m.line);
+        self.matches.clear();
+        self.current = None;
+        self.error = None;
+
+        if self.query.is_empty() {
+            return;
+        }
+
+        let regex = match smart_case_regex(&self.query) {
+            Ok(regex) => regex,
+            Err(e) => {
+                self.error = Some(e.to_string());
+                return;
+            }
+        };
+
+        for (line, text) in lines.iter().enumerate() {
+            for m in regex.find_iter(text) {
+                if m.start() == m.end() {
+                    continue;
+                }
+                self.matches.push(SearchMatch {
+                    line,
+                    start: m.start(),
+                    end: m.end(),
+                });
+            }
+        }
+
+        if let Some(line) = current_line {
+            self.current = self.first_from(line);
+        }
+    }
+
+    /// The current match, if any
+    pub fn current_match(&self) -> Option<SearchMatch> {
+        self.current.and_then(|i| self.matches.get(i)).copied()
+    }
+
+    /// Make the first match on or after `line` current (wrapping around)
+    pub fn jump_from(&mut self, line: usize) -> Option<SearchMatch> {
+        self.current = self.first_from(line);
+        self.current_match()
+    }
+
+    /// Move to the next (or previous) match, wrapping around
+    pub fn step(&mut self, forward: bool) -> Option<SearchMatch> {
+        if self.matches.is_empty() {
+            return None;
+        }
+
+        let len = self.matches.len();
+        self.current = Some(match self.current {
+            Some(i) if forward => (i + 1) % len,
+            Some(i) => (i + len - 1) % len,
+            None if forward => 0,
+            None => len - 1,
+        });
+        self.current_match()
+    }
+
+    /// Matches on one line
+    pub fn matches_on(&self, line: usize) -> impl Iterator<Item = (usize, &SearchMatch)> {
+        let start = self.matches.partition_point(|m| m.line < line);
+        self.matches[start..]
+            .iter()
+            .take_while(move |m| m.line == line)
+            .enumerate()
+            .map(move |(i, m)| (start + i, m))
+    }
+
+    /// Short status for the preview title, e.g. "/error 3/17"
+    pub fn status(&self) -> String {
+        if self.error.is_some() {
+            format!("/{} invalid", self.query)
+        } else if self.matches.is_empty() {
+            format!("/{} no match", self.query)
+        } else {
+            let current = self.current.map_or(0, |i| i + 1);
+            format!("/{} {}/{}", self.query, current, self.matches.len())
+        }
+    }
+
+    /// Index of the first match on or after `line`, wrapping to the first match
+    fn first_from(&self, line: usize) -> Option<usize> {
+        if self.matches.is_empty() {
+            return None;
+        }
+        Some(
+            self.matches
+                .iter()
+                .position(|m| m.line >= line)
+                .unwrap_or(0),
+        )
+    }
+}
+
+/// Compile a pattern with smart case
+///
+/// The pattern is case-insensitive unless it contains an uppercase letter.
+pub fn smart_case_regex(pattern: &str) -> Result<Regex, regex::Error> {
+    let case_insensitive = !pattern.chars().any(char::is_uppercase);
+    RegexBuilder::new(pattern)
+        .case_insensitive(case_insensitive)
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines() -> Vec<String> {
+        ["ok build", "ERROR one", "fine", "error two, error three"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    fn search(query: &str) -> ScrollbackSearch {
+        let mut search = ScrollbackSearch {
+            query: query.to_string(),
+            ..Default::default()
+        };
+        search.update(&lines());
+        search
+    }
+
+    #[test]
+    fn test_smart_case() {
+        assert_eq!(search("error").matches.len(), 3);
+        assert_eq!(search("ERROR").matches.len(), 1);
+    }
+
+    #[test]
+    fn test_step_wraps() {
+        let mut search = search("error");
+        assert_eq!(search.jump_from(2).map(|m| m.line), Some(3));
+        assert_eq!(search.status(), "/error 2/3");
+        assert_eq!(search.step(true).map(|m| m.start), Some(11));
+        assert_eq!(search.step(true).map(|m| m.line), Some(1));
+        assert_eq!(search.step(false).map(|m| m.line), Some(3));
+    }
+
+    #[test]
+    fn test_matches_on_line() {
+        let search = search("error");
+        let on_line: Vec<usize> = search.matches_on(3).map(|(i, _)| i).collect();
+        assert_eq!(on_line, vec![1, 2]);
+        assert_eq!(search.matches_on(2).count(), 0);
+    }
+
+    #[test]
+    fn test_invalid_and_empty() {
+        let invalid = search("err(");
+        assert!(invalid.error.is_some());
+        assert_eq!(invalid.status(), "/err( invalid");
+
+        let empty = search("");
+        assert!(empty.matches.is_empty());
+        assert!(empty.error.is_none());
+    }
+}
@@ -0,0 +1,341 @@
+//! Scrollback selection
+//!
+//! Cursor motions and visual selection over captured preview lines, used by
+//! the preview copy mode. Columns count characters, not bytes.
+
+/// Cursor motion in copy mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Motion {
+    Left,
+    Right,
+    Up,
+    Down,
+    /// Start of the line (`0`)
+    LineStart,
+    /// First non-blank character (`^`)
+    FirstNonBlank,
+    /// Last character of the line (`$`)
+    LineEnd,
+    /// Start of the next word (`w`)
+    WordForward,
+    /// Start of the previous word (`b`)
+    WordBackward,
+    /// End of the current or next word (`e`)
+    WordEnd,
+    /// First line (`g`)
+    Top,
+    /// Last non-blank line (`G`)
+    Bottom,
+    /// Half a page up (`Ctrl-u`)
+    HalfPageUp,
+    /// Half a page down (`Ctrl-d`)
+    HalfPageDown,
+}
+
+/// Shape of a visual selection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionKind {
+    /// From the anchor to the cursor, like vim's `v`
+    Char,
+    /// Whole lines, like vim's `V`
+    Line,
+    /// A rectangle, like vim's `Ctrl-v`
+    Block,
+}
+
+impl SelectionKind {
+    /// Mode name shown in the preview title
+    pub fn label(&self) -> &'static str {
+        match self {
+            SelectionKind::Char => "VISUAL",
+            SelectionKind::Line => "VISUAL LINE",
+            SelectionKind::Block => "VISUAL BLOCK",
+        }
+    }
+}
+
+/// Cursor and selection of the preview copy mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CopyMode {
+    /// Cursor line
+    pub line: usize,
+    /// Cursor column (may be past the end of a short line)
+    pub col: usize,
+    /// Where the visual selection started, and its shape
+    pub visual: Option<((usize, usize), SelectionKind)>,
+}
+
+impl CopyMode {
+    /// Start copy mode with the cursor at the start of a line
+    pub fn new(line: usize) -> Self {
+        Self {
+            line,
+            col: 0,
+            visual: None,
+        }
+    }
+
+    /// Move the cursor; `page` is the visible height of the preview
+    pub fn motion(&mut self, motion: Motion, lines: &[String], page: usize) {
+        let last_line = lines.len().saturating_sub(1);
+        let col = self.cursor_col(lines);
+
+        match motion {
+            Motion::Left => self.col = col.saturating_sub(1),
+            Motion::Right => self.col = (col + 1).min(last_col(line_at(lines, self.line))),
+            Motion::Up => self.line = self.line.saturating_sub(1),
+            Motion::Down => self.line = (self.line + 1).min(last_line),
+            Motion::LineStart => self.col = 0,
+            Motion::FirstNonBlank => self.col = first_non_blank(line_at(lines, self.line)),
+            Motion::LineEnd => self.col = last_col(line_at(lines, self.line)),
+            Motion::WordForward => {
+                let chars: Vec<char> = line_at(lines, self.line).chars().collect();
+                match next_word_start(&chars, col) {
+                    Some(next) => self.col = next,
+                    None if self.line < last_line => {
+                        self.line += 1;
+                        self.col = first_non_blank(line_at(lines, self.line));
+                    }
+                    None => self.col = last_col(line_at(lines, self.line)),
+                }
+            }
+            Motion::WordBackward => {
+                let chars: Vec<char> = line_at(lines, self.line).chars().collect();
+                match prev_word_start(&chars, col) {
+                    Some(prev) => self.col = prev,
+                    None if self.line > 0 => {
+                        self.line -= 1;
+                        let chars: Vec<char> = line_at(lines, self.line).chars().collect();
+                        self.col = prev_word_start(&chars, chars.len()).unwrap_or(0);
+                    }
+                    None => self.col = 0,
+                }
+            }
+            Motion::WordEnd => {
+                let chars: Vec<char> = line_at(lines, self.line).chars().collect();
+                self.col = word_end(&chars, col).unwrap_or(col);
+            }
+            Motion::Top => self.line = 0,
+            Motion::Bottom => {
+                self.line = lines
+                    .iter()
+                    .rposition(|line| !line.trim().is_empty())
+                    .unwrap_or(last_line);
+            }
+            Motion::HalfPageUp => self.line = self.line.saturating_sub((page / 2).max(1)),
+            Motion::HalfPageDown => self.line = (self.line + (page / 2).max(1)).min(last_line),
+        }
+    }
+
+    /// Start a visual selection, switch its shape, or end it when the same
+    /// shape is chosen again
+    pub fn toggle_visual(&mut self, kind: SelectionKind, lines: &[String]) {
+        self.visual = match self.visual {
+            Some((_, current)) if current == kind => None,
+            Some((anchor, _)) => Some((anchor, kind)),
+            None => Some(((self.line, self.cursor_col(lines)), kind)),
+        };
+    }
+
+    /// Cursor column clamped to the cursor line
+    pub fn cursor_col(&self, lines: &[String]) -> usize {
+        self.col.min(last_col(line_at(lines, self.line)))
+    }
+
+    /// Columns selected on a line as a half-open range, if any
+    ///
+    /// Line selections return `usize::MAX` as the end so they cover the
+    /// whole line whatever its length.
+    pub fn selected_columns(&self, line: usize, lines: &[String]) -> Option<(usize, usize)> {
+        let ((anchor_line, anchor_col), kind) = self.visual?;
+        let cursor = (self.line, self.cursor_col(lines));
+        let (start, end) = if (anchor_line, anchor_col) <= cursor {
+            ((anchor_line, anchor_col), cursor)
+        } else {
+            (cursor, (anchor_line, anchor_col))
+        };
+
+        if line < start.0 || line > end.0 {
+            return None;
+        }
+
+        match kind {
+            SelectionKind::Line => Some((0, usize::MAX)),
+            SelectionKind::Block => {
+                let left = anchor_col.min(cursor.1);
+                let right = anchor_col.max(cursor.1);
+                Some((left, right + 1))
+            }
+            SelectionKind::Char => {
+                let from = if line == start.0 { start.1 } else { 0 };
+                let to = if line == end.0 { end.1 + 1 } else { usize::MAX };
+                Some((from, to))
+            }
+        }
+    }
+
+    /// Text of the selection, or of the cursor line when nothing is selected
+    ///
+    /// Trailing whitespace is dropped from every line.
+    pub fn selected_text(&self, lines: &[String]) -> String {
+        if self.visual.is_none() {
+            return line_at(lines, self.line).trim_end().to_string();
+        }
+
+        let selected: Vec<String> = (0..lines.len())
+            .filter_map(|i| {
+                let (from, to) = self.selected_columns(i, lines)?;
+                let text: String = lines[i]
+                    .chars()
+                    .skip(from)
+                    .take(to.saturating_sub(from))
+                    .collect();
+                Some(text.trim_end().to_string())
+            })
+            .collect();
+
+        selected.join("\n")
+    }
+}
+
+/// A line by index, or the empty string past the end
+fn line_at(lines: &[String], line: usize) -> &str {
+    lines.get(line).map(String::as_str).unwrap_or("")
+}
+
+/// Column of the last character of a line
+fn last_col(line: &str) -> usize {
+    line.chars().count().saturating_sub(1)
+}
+
+/// Column of the first non-blank character of a line
+fn first_non_blank(line: &str) -> usize {
+    line.chars().position(|c| !c.is_whitespace()).unwrap_or(0)
+}
+
+/// Character class for word motions: blank, word or punctuation
+fn class(c: char) -> u8 {
+    if c.is_whitespace() {
+        0
+    } else if c.is_alphanumeric() || c == '_' {
+        1
+    } else {
+        2
+    }
+}
+
+/// Start of the next word after `col` on the same line
+fn next_word_start(chars: &[char], col: usize) -> Option<usize> {
+    let current = class(*chars.get(col)?);
+    let mut i = col;
+    while i < chars.len() && class(chars[i]) == current && current != 0 {
+        i += 1;
+    }
+    while i < chars.len() && class(chars[i]) == 0 {
+        i += 1;
+    }
+    (i < chars.len()).then_some(i)
+}
+
+/// Start of the word before `col` on the same line
+fn prev_word_start(chars: &[char], col: usize) -> Option<usize> {
+    let mut i = col.min(chars.len());
+    while i > 0 && class(chars[i - 1]) == 0 {
+        i -= 1;
+    }
+    if i == 0 {
+        return None;
+    }
+    let current = class(chars[i - 1]);
+    while i > 0 && class(chars[i - 1]) == current {
+        i -= 1;
+    }
+    Some(i)
+}
+
+/// End of the word at or after `col` on the same line
+fn word_end(chars: &[char], col: usize) -> Option<usize> {
+    let mut i = col + 1;
+    while i < chars.len() && class(chars[i]) == 0 {
+        i += 1;
+    }
+    if i >= chars.len() {
+        return None;
+    }
+    let current = class(chars[i]);
+    while i + 1 < chars.len() && class(chars[i + 1]) == current {
+        i += 1;
+    }
+    Some(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines() -> Vec<String> {
+        ["$ cargo build", "  Compiling sesh v0.1", "", "error: oops"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_word_motions() {
+        let lines = lines();
+        let mut copy = CopyMode::new(0);
+
+        copy.motion(Motion::WordForward, &lines, 10);
+        assert_eq!((copy.line, copy.col), (0, 2));
+        copy.motion(Motion::WordEnd, &lines, 10);
+        assert_eq!(copy.col, 6);
+        copy.motion(Motion::WordForward, &lines, 10);
+        copy.motion(Motion::WordForward, &lines, 10);
+        assert_eq!((copy.line, copy.col), (1, 2));
+        copy.motion(Motion::WordBackward, &lines, 10);
+        assert_eq!((copy.line, copy.col), (0, 8));
+    }
+
+    #[test]
+    fn test_line_motions_clamp() {
+        let lines = lines();
+        let mut copy = CopyMode::new(1);
+
+        copy.motion(Motion::LineEnd, &lines, 10);
+        assert_eq!(copy.col, 20);
+        copy.motion(Motion::Down, &lines, 10);
+        assert_eq!(copy.cursor_col(&lines), 0);
+        copy.motion(Motion::Down, &lines, 10);
+        assert_eq!(copy.cursor_col(&lines), 10);
+        copy.motion(Motion::Top, &lines, 10);
+        copy.motion(Motion::FirstNonBlank, &lines, 10);
+        assert_eq!((copy.line, copy.col), (0, 0));
+        copy.motion(Motion::Bottom, &lines, 10);
+        assert_eq!(copy.line, 3);
+    }
+
+    #[test]
+    fn test_selections() {
+        let lines = lines();
+        let mut copy = CopyMode::new(0);
+        assert_eq!(copy.selected_text(&lines), "$ cargo build");
+
+        copy.col = 2;
+        copy.toggle_visual(SelectionKind::Char, &lines);
+        copy.line = 1;
+        copy.col = 10;
+        assert_eq!(copy.selected_text(&lines), "cargo build\n  Compiling");
+
+        copy.toggle_visual(SelectionKind::Line, &lines);
+        assert_eq!(
+            copy.selected_text(&lines),
+            "$ cargo build\n  Compiling sesh v0.1"
+        );
+
+        copy.toggle_visual(SelectionKind::Block, &lines);
+        assert_eq!(copy.selected_text(&lines), "cargo bui\nCompiling");
+
+        copy.toggle_visual(SelectionKind::Block, &lines);
+        assert!(copy.visual.is_none());
+    }
+}
@@ -0,0 +1,69 @@
+//! Sending text
+//!
+//! Types text into windows of local or remote sessions with screen's
+//! `stuff` command.
+
+use anyhow::Result;
+
+use super::{local, remote};
+use crate::config::Settings;
+
+/// Key sent for Enter
+const ENTER: &str = "\r";
+
+/// Escape text so `stuff` types it literally
+///
+/// screen's command parser treats `^X` as a control character, `\` as an
+/// escape and `$` as the start of a variable.
+pub fn escape_stuff(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '^' | '$') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Keys to stuff for a line of text, optionally followed by Enter
+pub fn keys_for(text: &str, enter: bool) -> String {
+    let mut keys = escape_stuff(text);
+    if enter {
+        keys.push_str(ENTER);
+    }
+    keys
+}
+
+/// Type text into a window (None for the session's current window)
+pub async fn send(
+    config: &Settings,
+    host: Option<&str>,
+    session: &str,
+    window: Option<usize>,
+    text: &str,
+    enter: bool,
+) -> Result<()> {
+    let keys = keys_for(text, enter);
+    match host {
+        Some(host) => remote::send_keys(config, host, session, window, &keys).await,
+        None => local::send_keys(session, window, &keys).await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_stuff() {
+        assert_eq!(escape_stuff("ls -la"), "ls -la");
+        assert_eq!(escape_stuff("echo ^C \\n $HOME"), "echo \\^C \\\\n \\$HOME");
+    }
+
+    #[test]
+    fn test_keys_for() {
+        assert_eq!(keys_for("make", true), "make\r");
+        assert_eq!(keys_for("y", false), "y");
+    }
+}
@@ -2,6 +2,7 @@
 
 use chrono::{DateTime, Local};
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::fmt;
 
 /// Status of a screen session
@@ -16,6 +17,8 @@ pub enum SessionStatus {
     /// Session status is unknown
     #[default]
     Unknown,
+    /// Discovered project without a running session
+    Project,
 }
 
 impl fmt::Display for SessionStatus {
@@ -25,6 +28,7 @@ impl fmt::Display for SessionStatus {
             SessionStatus::Attached => write!(f, "attached"),
             SessionStatus::Multi => write!(f, "multi"),
             SessionStatus::Unknown => write!(f, "unknown"),
+            SessionStatus::Project => write!(f, "project"),
         }
     }
 }
@@ -62,6 +66,12 @@ pub struct Session {
     /// Git status (clean = true, dirty = false)
     pub git_clean: Option<bool>,
 
+    /// Root of the git repository containing the working directory
+    pub repo_root: Option<String>,
+
+    /// Names of the session's windows (if known)
+    pub window_names: Vec<String>,
+
     /// List of attached users (for multiuser sessions)
     pub attached_users: Vec<String>,
 }
@@ -80,6 +90,8 @@ impl Session {
             working_dir: None,
             git_branch: None,
             git_clean: None,
+            repo_root: None,
+            window_names: Vec::new(),
             attached_users: Vec::new(),
         }
     }
@@ -98,6 +110,18 @@ impl Session {
         self.host.is_none()
     }
 
+    /// Create a virtual session for a discovered project
+    pub fn project(name: String, dir: String) -> Self {
+        let mut session = Self::new(name.clone(), name, 0, SessionStatus::Project);
+        session.working_dir = Some(dir);
+        session
+    }
+
+    /// Check if this is a discovered project rather than a running session
+    pub fn is_virtual(&self) -> bool {
+        self.status == SessionStatus::Project
+    }
+
     /// Check if session is attached
     pub fn is_attached(&self) -> bool {
         matches!(self.status, SessionStatus::Attached | SessionStatus::Multi)
@@ -195,8 +219,15 @@ pub struct Preview {
 
     /// Whether preview is being updated
     pub updating: bool,
+
+    /// Per line, whether it changed since the previous capture
+    pub changed: Vec<bool>,
 }
 
+/// How many lines dropped from the top of the scrollback are tried when
+/// aligning two captures
+const MAX_DIFF_SHIFT: usize = 200;
+
 impl Preview {
     /// Create a new empty preview
     pub fn new() -> Self {
@@ -214,6 +245,30 @@ impl Preview {
             .collect()
     }
 
+    /// Mark the lines that differ from the previous capture
+    pub fn diff_against(&mut self, previous: &Preview) {
+        self.changed = changed_lines(&previous.lines, &self.lines);
+    }
+
+    /// Whether a line (0-based) changed since the previous capture
+    pub fn is_changed(&self, index: usize) -> bool {
+        self.changed.get(index).copied().unwrap_or(false)
+    }
+
+    /// Hash of the content, ignoring trailing blank lines
+    pub fn fingerprint(&self) -> u64 {
+        use std::hash::{Hash, Hasher};
+
+        let end = self
+            .lines
+            .iter()
+            .rposition(|line| !line.trim().is_empty())
+            .map_or(0, |i| i + 1);
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        self.lines[..end].hash(&mut hasher);
+        hasher.finish()
+    }
+
     /// Scroll up by n lines
     pub fn scroll_up(&mut self, n: usize) {
         self.scroll_offset = self.scroll_offset.saturating_sub(n);
@@ -226,34 +281,226 @@ impl Preview {
     }
 }
 
-/// Grouped sessions by host
+/// Per line of `current`, whether it differs from `previous`
+///
+/// When the scrollback is full, new output pushes old lines off the top, so
+/// the captures are aligned on the shift with the longest common run first.
+/// Nothing is reported as changed against an empty previous capture.
+pub fn changed_lines(previous: &[String], current: &[String]) -> Vec<bool> {
+    if previous.is_empty() {
+        return vec![false; current.len()];
+    }
+
+    let common = |shift: usize| {
+        previous[shift..]
+            .iter()
+            .zip(current)
+            .take_while(|(a, b)| a == b)
+            .count()
+    };
+    let shift = (0..previous.len().min(MAX_DIFF_SHIFT + 1))
+        .max_by_key(|&shift| (common(shift), std::cmp::Reverse(shift)))
+        .unwrap_or(0);
+
+    current
+        .iter()
+        .enumerate()
+        .map(|(i, line)| previous.get(i + shift) != Some(line))
+        .collect()
+}
+
+/// Sort order for the session list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SortKey {
+    /// Most frequently and recently used first
+    #[default]
+    Frecency,
+    /// Alphabetical by name
+    Name,
+    /// Newest first
+    Age,
+    /// Attached sessions first
+    Status,
+    /// Most windows first
+    Windows,
+    /// Local first, then by host name
+    Host,
+}
+
+impl SortKey {
+    /// All sort keys in cycle order
+    pub fn all() -> [Self; 6] {
+        [
+            Self::Frecency,
+            Self::Name,
+            Self::Age,
+            Self::Status,
+            Self::Windows,
+            Self::Host,
+        ]
+    }
+
+    /// Next sort key in the cycle
+    pub fn next(self) -> Self {
+        let all = Self::all();
+        let idx = all.iter().position(|k| *k == self).unwrap_or(0);
+        all[(idx + 1) % all.len()]
+    }
+
+    /// Compare two sessions by this key
+    ///
+    /// Frecency is not stored on the session, so it compares equal here and
+    /// is ranked by the caller.
+    pub fn compare(self, a: &Session, b: &Session) -> Ordering {
+        match self {
+            Self::Frecency => Ordering::Equal,
+            Self::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+            Self::Age => b.created.cmp(&a.created),
+            Self::Status => status_rank(a.status).cmp(&status_rank(b.status)),
+            Self::Windows => b.window_count.cmp(&a.window_count),
+            Self::Host => (a.host.is_some(), &a.host).cmp(&(b.host.is_some(), &b.host)),
+        }
+    }
+}
+
+impl fmt::Display for SortKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SortKey::Frecency => write!(f, "frecency"),
+            SortKey::Name => write!(f, "name"),
+            SortKey::Age => write!(f, "age"),
+            SortKey::Status => write!(f, "status"),
+            SortKey::Windows => write!(f, "windows"),
+            SortKey::Host => write!(f, "host"),
+        }
+    }
+}
+
+/// Grouping for the session list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum GroupBy {
+    /// Flat list without headers
+    None,
+    /// Local sessions, then one group per remote host
+    #[default]
+    Host,
+    /// One group per git repository root
+    Repo,
+    /// One group per session status
+    Status,
+}
+
+impl GroupBy {
+    /// All groupings in cycle order
+    pub fn all() -> [Self; 4] {
+        [Self::Host, Self::Repo, Self::Status, Self::None]
+    }
+
+    /// Next grouping in the cycle
+    pub fn next(self) -> Self {
+        let all = Self::all();
+        let idx = all.iter().position(|g| *g == self).unwrap_or(0);
+        all[(idx + 1) % all.len()]
+    }
+
+    /// Group label and ordering rank for a session
+    fn group_of(self, session: &Session) -> (String, (u8, String)) {
+        match self {
+            Self::None => (String::new(), (0, String::new())),
+            Self::Host => match &session.host {
+                None => ("LOCAL".to_string(), (0, String::new())),
+                Some(host) => (format!("REMOTE ({})", host), (1, host.clone())),
+            },
+            Self::Repo => match &session.repo_root {
+                Some(root) => (root.clone(), (0, root.clone())),
+                None => ("NO REPOSITORY".to_string(), (1, String::new())),
+            },
+            Self::Status => (
+                session.status.to_string().to_uppercase(),
+                (status_rank(session.status), String::new()),
+            ),
+        }
+    }
+}
+
+impl fmt::Display for GroupBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GroupBy::None => write!(f, "none"),
+            GroupBy::Host => write!(f, "host"),
+            GroupBy::Repo => write!(f, "repo"),
+            GroupBy::Status => write!(f, "status"),
+        }
+    }
+}
+
+/// Display rank of a session status (attached first)
+fn status_rank(status: SessionStatus) -> u8 {
+    match status {
+        SessionStatus::Attached => 0,
+        SessionStatus::Multi => 1,
+        SessionStatus::Detached => 2,
+        SessionStatus::Unknown => 3,
+        SessionStatus::Project => 4,
+    }
+}
+
+/// A group of sessions shown under one header
 #[derive(Debug, Clone)]
 pub struct SessionGroup {
-    /// Host name (None for local)
-    pub host: Option<String>,
+    /// Group label (host, repository root or status)
+    pub label: String,
 
-    /// Sessions in this group
-    pub sessions: Vec<Session>,
+    /// Indices into the session list, in display order
+    pub sessions: Vec<usize>,
 }
 
 impl SessionGroup {
     /// Create a new session group
-    pub fn new(host: Option<String>) -> Self {
+    pub fn new(label: String) -> Self {
         Self {
-            host,
+            label,
             sessions: Vec::new(),
         }
     }
 
     /// Get display name for the group
     pub fn display_name(&self) -> &str {
-        self.host.as_deref().unwrap_or("LOCAL")
+        &self.label
     }
+}
 
-    /// Check if this is the local group
-    pub fn is_local(&self) -> bool {
-        self.host.is_none()
+/// Split ordered session indices into groups
+///
+/// Sessions keep their relative order within each group.
+pub fn group_sessions(
+    sessions: &[Session],
+    order: &[usize],
+    group_by: GroupBy,
+) -> Vec<SessionGroup> {
+    let mut groups: Vec<((u8, String), SessionGroup)> = Vec::new();
+
+    for &idx in order {
+        let session = match sessions.get(idx) {
+            Some(session) => session,
+            None => continue,
+        };
+        let (label, rank) = group_by.group_of(session);
+
+        match groups.iter_mut().find(|(_, g)| g.label == label) {
+            Some((_, group)) => group.sessions.push(idx),
+            None => {
+                let mut group = SessionGroup::new(label);
+                group.sessions.push(idx);
+                groups.push((rank, group));
+            }
+        }
     }
+
+    groups.sort_by(|a, b| a.0.cmp(&b.0));
+    groups.into_iter().map(|(_, group)| group).collect()
 }
 
 #[cfg(test)]
@@ -280,6 +527,16 @@ mod tests {
         assert_eq!(format!("{}", SessionStatus::Detached), "detached");
         assert_eq!(format!("{}", SessionStatus::Attached), "attached");
         assert_eq!(format!("{}", SessionStatus::Multi), "multi");
+        assert_eq!(format!("{}", SessionStatus::Project), "project");
+    }
+
+    #[test]
+    fn test_project_session() {
+        let session = Session::project("api".to_string(), "/home/me/src/api".to_string());
+        assert!(session.is_virtual());
+        assert!(session.is_local());
+        assert!(!session.is_attached());
+        assert_eq!(session.working_dir.as_deref(), Some("/home/me/src/api"));
     }
 
     #[test]
@@ -289,6 +546,7 @@ mod tests {
             total_lines: 100,
             scroll_offset: 0,
             updating: false,
+            changed: Vec::new(),
         };
 
         assert_eq!(preview.scroll_offset, 0);
@@ -307,4 +565,68 @@ mod tests {
         preview.scroll_down(200, 20);
         assert_eq!(preview.scroll_offset, 80); // 100 - 20
     }
+
+    fn named(name: &str, status: SessionStatus, host: Option<&str>) -> Session {
+        let mut session = Session::new(format!("1.{}", name), name.to_string(), 1, status);
+        session.host = host.map(String::from);
+        session
+    }
+
+    #[test]
+    fn test_sort_key_cycle() {
+        assert_eq!(SortKey::default(), SortKey::Frecency);
+        assert_eq!(SortKey::Frecency.next(), SortKey::Name);
+        assert_eq!(SortKey::Host.next(), SortKey::Frecency);
+        assert_eq!(GroupBy::None.next(), GroupBy::Host);
+    }
+
+    #[test]
+    fn test_sort_key_compare() {
+        let mut sessions = [
+            named("web", SessionStatus::Detached, Some("prod")),
+            named("Api", SessionStatus::Detached, None),
+            named("db", SessionStatus::Attached, None),
+        ];
+
+        sessions.sort_by(|a, b| SortKey::Name.compare(a, b));
+        let names: Vec<_> = sessions.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["Api", "db", "web"]);
+
+        sessions.sort_by(|a, b| SortKey::Status.compare(a, b));
+        assert_eq!(sessions[0].name, "db");
+
+        sessions.sort_by(|a, b| SortKey::Host.compare(a, b));
+        assert_eq!(sessions[2].name, "web");
+    }
+
+    #[test]
+    fn test_group_sessions_by_host() {
+        let sessions = vec![
+            named("web", SessionStatus::Detached, Some("prod")),
+            named("api", SessionStatus::Detached, None),
+            named("db", SessionStatus::Attached, Some("db1")),
+            named("ci", SessionStatus::Attached, None),
+        ];
+
+        let groups = group_sessions(&sessions, &[0, 1, 2, 3], GroupBy::Host);
+        let labels: Vec<_> = groups.iter().map(|g| g.display_name()).collect();
+        assert_eq!(labels, vec!["LOCAL", "REMOTE (db1)", "REMOTE (prod)"]);
+        assert_eq!(groups[0].sessions, vec![1, 3]);
+    }
+
+    #[test]
+    fn test_group_sessions_by_status() {
+        let sessions = vec![
+            named("a", SessionStatus::Detached, None),
+            named("b", SessionStatus::Attached, None),
+        ];
+
+        let groups = group_sessions(&sessions, &[0, 1], GroupBy::Status);
+        assert_eq!(groups[0].label, "ATTACHED");
+        assert_eq!(groups[1].label, "DETACHED");
+
+        let flat = group_sessions(&sessions, &[1, 0], GroupBy::None);
+        assert_eq!(flat.len(), 1);
+        assert_eq!(flat[0].sessions, vec![1, 0]);
+    }
 }
@@ -0,0 +1,138 @@
+//! Window management
+//!
+//! Lists, captures, creates, renames, kills and renumbers windows of local or
+//! remote sessions.
+
+use anyhow::Result;
+
+use super::types::{Preview, Window};
+use super::{local, remote};
+use crate::config::Settings;
+
+/// Operation on the windows of a session
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WindowOp {
+    /// Create a window, optionally titled
+    New(Option<String>),
+    /// Set the title of a window
+    Rename(usize, String),
+    /// Kill a window
+    Kill(usize),
+    /// Give a window another number (screen swaps it with a window already there)
+    Move(usize, usize),
+    /// Make a window the session's current window
+    Select(usize),
+}
+
+impl WindowOp {
+    /// Message shown after the operation succeeded
+    pub fn done_message(&self) -> String {
+        match self {
+            WindowOp::New(Some(name)) => format!("Created window '{}'", name),
+            WindowOp::New(None) => "Created window".to_string(),
+            WindowOp::Rename(window, name) => format!("Renamed window {} to '{}'", window, name),
+            WindowOp::Kill(window) => format!("Killed window {}", window),
+            WindowOp::Move(window, number) => format!("Moved window {} to {}", window, number),
+            WindowOp::Select(window) => format!("Selected window {}", window),
+        }
+    }
+}
+
+/// List the windows of a session on a host (None for local)
+pub async fn list(config: &Settings, host: Option<&str>, session: &str) -> Result<Vec<Window>> {
+    match host {
+        Some(host) => remote::list_windows(config, host, session).await,
+        None => local::list_windows(session).await,
+    }
+}
+
+/// Capture the scrollback of a window on a host (None for local)
+pub async fn capture(
+    config: &Settings,
+    host: Option<&str>,
+    session: &str,
+    window: Option<usize>,
+) -> Result<Preview> {
+    match host {
+        Some(host) => remote::get_preview(config, host, session, window).await,
+        None => local::get_preview(session, window).await,
+    }
+}
+
+/// Apply an operation to a session on a host (None for local)
+pub async fn run(
+    config: &Settings,
+    host: Option<&str>,
+    session: &str,
+    op: &WindowOp,
+) -> Result<()> {
+    match (host, op) {
+        (Some(host), WindowOp::New(name)) => {
+            remote::create_window(config, host, session, name.as_deref()).await
+        }
+        (Some(host), WindowOp::Rename(window, name)) => {
+            remote::rename_window(config, host, session, *window, name).await
+        }
+        (Some(host), WindowOp::Kill(window)) => {
+            remote::kill_window(config, host, session, *window).await
+        }
+        (Some(host), WindowOp::Move(window, number)) => {
+            remote::move_window(config, host, session, *window, *number).await
+        }
+        (Some(host), WindowOp::Select(window)) => {
+            remote::select_window(config, host, session, *window).await
+        }
+        (None, WindowOp::New(name)) => local::create_window(session, name.as_deref()).await,
+        (None, WindowOp::Rename(window, name)) => {
+            local::rename_window(session, *window, name).await
+        }
+        (None, WindowOp::Kill(window)) => local::kill_window(session, *window).await,
+        (None, WindowOp::Move(window, number)) => {
+            local::move_window(session, *window, *number).await
+        }
+        (None, WindowOp::Select(window)) => local::select_window(session, *window).await,
+    }
+}
+
+/// Number a window moves to when shifted one place towards the start or end
+///
+/// Moving onto a neighbour's number swaps the two windows.
+pub fn neighbour_number(windows: &[Window], index: usize, forward: bool) -> Option<usize> {
+    let neighbour = if forward {
+        index.checked_add(1)?
+    } else {
+        index.checked_sub(1)?
+    };
+    windows.get(neighbour).map(|w| w.number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neighbour_number() {
+        let windows = vec![
+            Window::new(0, "shell".to_string()),
+            Window::new(2, "vim".to_string()),
+            Window::new(5, "logs".to_string()),
+        ];
+
+        assert_eq!(neighbour_number(&windows, 1, false), Some(0));
+        assert_eq!(neighbour_number(&windows, 1, true), Some(5));
+        assert_eq!(neighbour_number(&windows, 0, false), None);
+        assert_eq!(neighbour_number(&windows, 2, true), None);
+    }
+
+    #[test]
+    fn test_done_message() {
+        assert_eq!(
+            WindowOp::Move(3, 1).done_message(),
+            "Moved window 3 to 1".to_string()
+        );
+        assert_eq!(
+            WindowOp::New(Some("logs".to_string())).done_message(),
+            "Created window 'logs'".to_string()
+        );
+    }
+}
@@ -0,0 +1,112 @@
+//! Dashboard widget
+//!
+//! Renders a grid of live preview tiles with activity-colored borders.
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::screen::dashboard::{grid_columns, Tile};
+
+use super::hitmap::{HitMap, HitTarget};
+use super::theme::Theme;
+
+/// Draw the dashboard grid (or the selected tile when zoomed)
+pub fn draw(frame: &mut Frame, app: &App, theme: &Theme, area: Rect, hits: &mut HitMap) {
+    if app.dashboard.is_empty() {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(theme.border())
+            .title(" Dashboard ");
+        let empty = Paragraph::new(Line::from(Span::styled("  Nothing to show", theme.muted())))
+            .block(block);
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    if app.preview_zoomed {
+        if let Some(tile) = app.dashboard.get(app.dashboard_index) {
+            hits.push(area, HitTarget::ListRow(app.dashboard_index));
+            draw_tile(frame, tile, true, theme, area);
+        }
+        return;
+    }
+
+    let columns = grid_columns(app.dashboard.len());
+    let rows = app.dashboard.len().div_ceil(columns);
+
+    let row_areas = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Ratio(1, rows as u32); rows])
+        .split(area);
+
+    for (row, row_area) in row_areas.iter().enumerate() {
+        let cells = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Ratio(1, columns as u32); columns])
+            .split(*row_area);
+
+        for (column, cell) in cells.iter().enumerate() {
+            let index = row * columns + column;
+            if let Some(tile) = app.dashboard.get(index) {
+                hits.push(*cell, HitTarget::ListRow(index));
+                draw_tile(frame, tile, index == app.dashboard_index, theme, *cell);
+            }
+        }
+    }
+}
+
+/// Draw one tile showing the newest lines of its preview
+fn draw_tile(frame: &mut Frame, tile: &Tile, selected: bool, theme: &Theme, area: Rect) {
+    let title_style = if selected {
+        theme.selected()
+    } else {
+        theme.normal()
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(if selected {
+            BorderType::Thick
+        } else {
+            BorderType::Plain
+        })
+        .border_style(theme.activity(tile.activity))
+        .title(Span::styled(format!(" {} ", tile.label()), title_style));
+
+    let height = area.height.saturating_sub(2) as usize;
+
+    let lines: Vec<Line> = if let Some(ref error) = tile.error {
+        vec![Line::from(Span::styled(error.clone(), theme.error()))]
+    } else if tile.preview.lines.is_empty() {
+        vec![Line::from(Span::styled(
+            "Waiting for output...",
+            theme.muted(),
+        ))]
+    } else {
+        tail(&tile.preview.lines, height)
+            .iter()
+            .map(|line| Line::from(Span::styled(line.clone(), theme.preview_content())))
+            .collect()
+    };
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Last `height` lines, ignoring trailing blank lines
+fn tail(lines: &[String], height: usize) -> &[String] {
+    &lines[tail_range(lines, height)]
+}
+
+/// Indices of the last `height` lines, ignoring trailing blank lines
+pub(super) fn tail_range(lines: &[String], height: usize) -> std::ops::Range<usize> {
+    let end = lines
+        .iter()
+        .rposition(|line| !line.trim().is_empty())
+        .map_or(0, |i| i + 1);
+    end.saturating_sub(height)..end
+}
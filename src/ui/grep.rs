@@ -0,0 +1,133 @@
+//! Grep results widget
+//!
+//! Lists scrollback matches across sessions as `session:window:line` with
+//! the context of the selected match below.
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::screen::grep::GrepHit;
+
+use super::hitmap::HitMap;
+use super::theme::Theme;
+
+/// Draw the match list and the context of the selected match
+pub fn draw(frame: &mut Frame, app: &App, theme: &Theme, area: Rect, hits: &mut HitMap) {
+    let title = format!(" Grep /{}/ ", app.grep_query);
+
+    if app.grep_hits.is_empty() {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(theme.border_focused())
+            .title(title);
+        let empty =
+            Paragraph::new(Line::from(Span::styled("  No matches", theme.muted()))).block(block);
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let selected = app.grep_hits.get(app.grep_index);
+    let context_height =
+        selected.map_or(0, |hit| hit.hit.before.len() + hit.hit.after.len() + 1) as u16 + 2;
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(context_height)])
+        .split(area);
+
+    let items: Vec<ListItem> = app
+        .grep_hits
+        .iter()
+        .map(|hit| {
+            let mut spans = vec![
+                Span::styled(hit.location(), theme.accent()),
+                Span::raw("  "),
+            ];
+            spans.extend(match_spans(hit, theme.normal(), theme));
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(theme.border_focused())
+                .title(title),
+        )
+        .highlight_style(theme.selected())
+        .highlight_symbol("> ");
+
+    let mut state = ListState::default();
+    state.select(Some(app.grep_index));
+
+    frame.render_stateful_widget(list, chunks[0], &mut state);
+    hits.push_list_rows(chunks[0], state.offset(), app.grep_hits.len());
+
+    if let Some(hit) = selected {
+        draw_context(frame, hit, theme, chunks[1]);
+    }
+}
+
+/// Draw the lines around a match with line numbers
+fn draw_context(frame: &mut Frame, hit: &GrepHit, theme: &Theme, area: Rect) {
+    let first = hit.hit.line - hit.hit.before.len();
+    let numbered = |offset: usize, text: &str| {
+        Line::from(vec![
+            Span::styled(format!("{:>5} ", first + offset + 1), theme.line_number()),
+            Span::styled(text.to_string(), theme.preview_content()),
+        ])
+    };
+
+    let mut lines: Vec<Line> = hit
+        .hit
+        .before
+        .iter()
+        .enumerate()
+        .map(|(offset, text)| numbered(offset, text))
+        .collect();
+
+    let mut spans = vec![Span::styled(
+        format!("{:>5} ", hit.hit.line + 1),
+        theme.accent(),
+    )];
+    spans.extend(match_spans(hit, theme.preview_content(), theme));
+    lines.push(Line::from(spans));
+
+    let after_start = hit.hit.before.len() + 1;
+    lines.extend(
+        hit.hit
+            .after
+            .iter()
+            .enumerate()
+            .map(|(offset, text)| numbered(after_start + offset, text)),
+    );
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(theme.border())
+        .title(format!(" {} {} ", hit.location(), hit.window.name));
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Split the matching line into spans with the match highlighted
+fn match_spans<'a>(hit: &GrepHit, style: Style, theme: &Theme) -> Vec<Span<'a>> {
+    let text = &hit.text;
+    let (start, end) = (hit.hit.start, hit.hit.end);
+    if end > text.len() || !text.is_char_boundary(start) || !text.is_char_boundary(end) {
+        return vec![Span::styled(text.clone(), style)];
+    }
+
+    vec![
+        Span::styled(text[..start].to_string(), style),
+        Span::styled(text[start..end].to_string(), theme.search_match()),
+        Span::styled(text[end..].to_string(), style),
+    ]
+}
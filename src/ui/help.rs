@@ -45,7 +45,8 @@ pub fn draw(frame: &mut Frame, _app: &App, theme: &Theme) {
                 ("g / Home", "Go to top"),
                 ("G / End", "Go to bottom"),
                 ("PgUp/PgDn", "Page up/down"),
-                ("Tab", "Switch host"),
+                ("Tab", "Focus list / preview"),
+                ("Shift-Tab", "Switch host"),
             ],
         ),
         (
@@ -58,7 +59,20 @@ pub fn draw(frame: &mut Frame, _app: &App, theme: &Theme) {
                 ("d", "Detach"),
                 ("x", "Kill"),
                 ("w", "View windows"),
+                ("D", "Dashboard of sessions/windows"),
+                ("W", "Preview next window"),
                 ("r", "Refresh"),
+                ("o", "Cycle sort order"),
+                ("O", "Cycle grouping"),
+                ("Space", "Mark / unmark session"),
+                ("V", "Mark range from last mark"),
+                ("*", "Mark all matching (again to clear)"),
+                ("c", "Send screen command to marked"),
+                (":", "Screen command console"),
+                ("s", "Grep all scrollback"),
+                ("T", "Save session as template"),
+                ("i", "Send text (to all marked, if any)"),
+                ("z / h / l", "Toggle/collapse/expand group"),
             ],
         ),
         (
@@ -66,8 +80,12 @@ pub fn draw(frame: &mut Frame, _app: &App, theme: &Theme) {
             vec![
                 ("Enter", "Select & attach"),
                 ("n", "New window"),
-                ("r", "Rename"),
+                ("R", "Rename"),
                 ("x", "Kill"),
+                ("< / >", "Swap with previous/next"),
+                ("m", "Move to number"),
+                ("Space / *", "Mark window / all windows"),
+                ("i", "Send text (to all marked, if any)"),
             ],
         ),
     ];
@@ -84,6 +102,7 @@ pub fn draw(frame: &mut Frame, _app: &App, theme: &Theme) {
                 ("/", "Start search"),
                 ("Enter", "Confirm"),
                 ("Esc", "Clear"),
+                ("n / N", "Next/prev preview match"),
             ],
         ),
         (
@@ -99,6 +118,11 @@ pub fn draw(frame: &mut Frame, _app: &App, theme: &Theme) {
             vec![
                 ("?", "Toggle help"),
                 ("p", "Toggle preview"),
+                ("f", "Follow preview output"),
+                ("Z", "Zoom preview"),
+                ("#", "Toggle line numbers"),
+                ("/ in preview", "Search scrollback (n/N)"),
+                ("v in preview", "Copy mode (v/V/Ctrl-v, y/Y)"),
                 ("Esc", "Back / Close"),
                 ("q", "Quit"),
                 ("Ctrl-c", "Force quit"),
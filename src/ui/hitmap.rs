@@ -0,0 +1,146 @@
+//! Mouse hit-testing
+//!
+//! Widgets record the screen regions they draw each frame so mouse events
+//! can be mapped back to list rows, tabs, hints and buttons.
+
+use crossterm::event::KeyCode;
+use ratatui::layout::Rect;
+
+/// What a recorded region refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HitTarget {
+    /// Row in the list of the current view (index into its items)
+    ListRow(usize),
+    /// Host tab in the header (index into `App::hosts`)
+    HostTab(usize),
+    /// The preview pane
+    Preview,
+    /// Key hint in the header or footer, clicking it acts like pressing the key
+    Hint(KeyCode),
+    /// Dialog button, clicking it acts like pressing the key
+    Button(KeyCode),
+}
+
+/// Regions recorded while drawing a frame
+#[derive(Debug, Clone, Default)]
+pub struct HitMap {
+    regions: Vec<(Rect, HitTarget)>,
+}
+
+impl HitMap {
+    /// Create an empty hit map
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a region
+    pub fn push(&mut self, area: Rect, target: HitTarget) {
+        if area.width > 0 && area.height > 0 {
+            self.regions.push((area, target));
+        }
+    }
+
+    /// Find the target at a screen position
+    ///
+    /// Regions recorded later (overlays, dialogs) take precedence.
+    pub fn hit(&self, column: u16, row: u16) -> Option<HitTarget> {
+        self.regions
+            .iter()
+            .rev()
+            .find(|(area, _)| {
+                column >= area.x
+                    && column < area.x + area.width
+                    && row >= area.y
+                    && row < area.y + area.height
+            })
+            .map(|(_, target)| *target)
+    }
+
+    /// Area of the first region with the given target
+    pub fn area_of(&self, target: HitTarget) -> Option<Rect> {
+        self.regions
+            .iter()
+            .find(|(_, t)| *t == target)
+            .map(|(area, _)| *area)
+    }
+
+    /// Record one region per visible row of a bordered list
+    ///
+    /// `offset` is the list's scroll offset after rendering.
+    pub fn push_list_rows(&mut self, area: Rect, offset: usize, len: usize) {
+        let inner = Rect {
+            x: area.x + 1,
+            y: area.y + 1,
+            width: area.width.saturating_sub(2),
+            height: area.height.saturating_sub(2),
+        };
+
+        for (i, index) in (offset..len).take(inner.height as usize).enumerate() {
+            self.push(
+                Rect {
+                    y: inner.y + i as u16,
+                    height: 1,
+                    ..inner
+                },
+                HitTarget::ListRow(index),
+            );
+        }
+    }
+}
+
+/// Key pressed by clicking a hint label such as `[Enter]`, `[x]` or `[o/O]`
+pub fn hint_key(label: &str) -> Option<KeyCode> {
+    let inner = label.trim_start_matches('[').trim_end_matches(']');
+    let first = inner.split('/').next().unwrap_or(inner);
+
+    match first {
+        "Enter" => Some(KeyCode::Enter),
+        "Esc" => Some(KeyCode::Esc),
+        "Tab" => Some(KeyCode::Tab),
+        "Space" => Some(KeyCode::Char(' ')),
+        _ => {
+            let mut chars = first.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Some(KeyCode::Char(c)),
+                _ => None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_later_regions_win() {
+        let mut hits = HitMap::new();
+        hits.push(Rect::new(0, 0, 80, 24), HitTarget::Preview);
+        hits.push(Rect::new(10, 5, 20, 1), HitTarget::Button(KeyCode::Enter));
+
+        assert_eq!(hits.hit(15, 5), Some(HitTarget::Button(KeyCode::Enter)));
+        assert_eq!(hits.hit(15, 6), Some(HitTarget::Preview));
+        assert_eq!(hits.hit(80, 0), None);
+    }
+
+    #[test]
+    fn test_list_rows_follow_offset() {
+        let mut hits = HitMap::new();
+        hits.push_list_rows(Rect::new(0, 0, 40, 5), 4, 6);
+
+        assert_eq!(hits.hit(0, 1), None);
+        assert_eq!(hits.hit(1, 1), Some(HitTarget::ListRow(4)));
+        assert_eq!(hits.hit(1, 2), Some(HitTarget::ListRow(5)));
+        assert_eq!(hits.hit(1, 3), None);
+    }
+
+    #[test]
+    fn test_hint_key() {
+        assert_eq!(hint_key("[Enter]"), Some(KeyCode::Enter));
+        assert_eq!(hint_key("[x]"), Some(KeyCode::Char('x')));
+        assert_eq!(hint_key("[o/O]"), Some(KeyCode::Char('o')));
+        assert_eq!(hint_key("[Enter/Esc]"), Some(KeyCode::Enter));
+        assert_eq!(hint_key("[Space]"), Some(KeyCode::Char(' ')));
+        assert_eq!(hint_key("[PgUp]"), None);
+    }
+}
@@ -10,11 +10,22 @@ use ratatui::{
 };
 
 use crate::app::App;
+use crate::screen::bulk::BulkResult;
 
-use super::{layout::centered_rect_fixed, theme::Theme};
+use super::{
+    hitmap::{HitMap, HitTarget},
+    layout::{centered_rect_fixed, key_hints},
+    theme::{Symbols, Theme},
+};
 
 /// Draw a text input dialog
-pub fn draw_input_dialog(frame: &mut Frame, app: &App, theme: &Theme, prompt: &str) {
+pub fn draw_input_dialog(
+    frame: &mut Frame,
+    app: &App,
+    theme: &Theme,
+    prompt: &str,
+    hits: &mut HitMap,
+) {
     let area = centered_rect_fixed(50, 5, frame.size());
 
     // Clear background
@@ -39,12 +50,13 @@ pub fn draw_input_dialog(frame: &mut Frame, app: &App, theme: &Theme, prompt: &s
         Span::styled(after_cursor, theme.normal()),
     ]);
 
-    let help_line = Line::from(vec![
-        Span::styled("[Enter] ", theme.key()),
-        Span::styled("Confirm  ", theme.key_desc()),
-        Span::styled("[Esc] ", theme.key()),
-        Span::styled("Cancel", theme.key_desc()),
-    ]);
+    let help_line = Line::from(key_hints(
+        &[("[Enter]", "Confirm"), ("[Esc]", "Cancel")],
+        theme,
+        dialog_line(area, 3),
+        HitTarget::Button,
+        hits,
+    ));
 
     let content = Paragraph::new(vec![
         Line::from(Span::raw("")),
@@ -58,7 +70,13 @@ pub fn draw_input_dialog(frame: &mut Frame, app: &App, theme: &Theme, prompt: &s
 }
 
 /// Draw a confirmation dialog
-pub fn draw_confirm_dialog(frame: &mut Frame, _app: &App, theme: &Theme, message: &str) {
+pub fn draw_confirm_dialog(
+    frame: &mut Frame,
+    _app: &App,
+    theme: &Theme,
+    message: &str,
+    hits: &mut HitMap,
+) {
     let area = centered_rect_fixed(50, 6, frame.size());
 
     // Clear background
@@ -73,20 +91,79 @@ pub fn draw_confirm_dialog(frame: &mut Frame, _app: &App, theme: &Theme, message
         Line::from(Span::raw("")),
         Line::from(Span::styled(message, theme.warning())),
         Line::from(Span::raw("")),
-        Line::from(vec![
-            Span::styled("[Enter] ", theme.key()),
-            Span::styled("Yes  ", theme.key_desc()),
-            Span::styled("[Esc] ", theme.key()),
-            Span::styled("No", theme.key_desc()),
-        ]),
+        Line::from(key_hints(
+            &[("[Enter]", "Yes"), ("[Esc]", "No")],
+            theme,
+            dialog_line(area, 3),
+            HitTarget::Button,
+            hits,
+        )),
     ])
     .block(block);
 
     frame.render_widget(content, area);
 }
 
+/// Draw the per-session results of a bulk operation
+pub fn draw_bulk_report(
+    frame: &mut Frame,
+    results: &[BulkResult],
+    theme: &Theme,
+    hits: &mut HitMap,
+) {
+    let height = (results.len() as u16 + 4).min(frame.size().height.saturating_sub(2));
+    let area = centered_rect_fixed(60, height, frame.size());
+
+    // Clear background
+    frame.render_widget(Clear, area);
+
+    let failed = results.iter().filter(|r| !r.is_ok()).count();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(theme.border_focused())
+        .title(format!(" Results ({} failed) ", failed));
+
+    let mut lines: Vec<Line> = results
+        .iter()
+        .map(|r| match &r.result {
+            Ok(msg) => Line::from(vec![
+                Span::styled(format!(" {} ", Symbols::GIT_CLEAN), theme.success()),
+                Span::styled(r.label.clone(), theme.normal()),
+                Span::styled(format!("  {}", msg), theme.muted()),
+            ]),
+            Err(e) => Line::from(vec![
+                Span::styled(format!(" {} ", Symbols::GIT_DIRTY), theme.error()),
+                Span::styled(r.label.clone(), theme.normal()),
+                Span::styled(format!("  {}", e.trim()), theme.error()),
+            ]),
+        })
+        .collect();
+
+    lines.push(Line::from(Span::raw("")));
+    let close_line = dialog_line(area, lines.len() as u16);
+    lines.push(Line::from(key_hints(
+        &[("[Enter/Esc]", "Close")],
+        theme,
+        close_line,
+        HitTarget::Button,
+        hits,
+    )));
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Screen row of a line inside a bordered dialog
+fn dialog_line(area: Rect, line: u16) -> Rect {
+    Rect {
+        x: area.x + 1,
+        y: area.y + 1 + line,
+        width: area.width.saturating_sub(2),
+        height: 1,
+    }
+}
+
 /// Draw the search bar
-pub fn draw_search_bar(frame: &mut Frame, app: &App, theme: &Theme) {
+pub fn draw_search_bar(frame: &mut Frame, query: &str, theme: &Theme) {
     let area = frame.size();
 
     // Position at bottom, above footer
@@ -99,7 +176,7 @@ pub fn draw_search_bar(frame: &mut Frame, app: &App, theme: &Theme) {
 
     let search_line = Line::from(vec![
         Span::styled(" / ", theme.prompt()),
-        Span::styled(&app.search_query, theme.normal()),
+        Span::styled(query, theme.normal()),
         Span::styled("_", theme.input_cursor()),
     ]);
 
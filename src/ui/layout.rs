@@ -9,13 +9,15 @@ use ratatui::{
     Frame,
 };
 
-use crate::app::{App, InputMode, View};
+use crate::app::{App, Focus, InputMode, View};
+use crate::config::settings::PreviewPosition;
 
-use super::{help, input, preview, sessions, templates, theme::Theme, windows};
+use super::hitmap::{hint_key, HitMap, HitTarget};
+use super::{dashboard, grep, help, input, preview, sessions, templates, theme::Theme, windows};
 
 /// Main draw function
-pub fn draw(frame: &mut Frame, app: &App) {
-    let theme = Theme::dark();
+pub fn draw(frame: &mut Frame, app: &App, hits: &mut HitMap) {
+    let theme = app.theme.clone();
     let size = frame.size();
 
     // Main layout: header, content, footer
@@ -29,30 +31,36 @@ pub fn draw(frame: &mut Frame, app: &App) {
         .split(size);
 
     // Draw header
-    draw_header(frame, app, &theme, chunks[0]);
+    draw_header(frame, app, &theme, chunks[0], hits);
 
     // Draw main content based on view
     match app.view {
         View::Sessions => {
-            draw_sessions_view(frame, app, &theme, chunks[1]);
+            draw_sessions_view(frame, app, &theme, chunks[1], hits);
         }
         View::Windows => {
-            draw_windows_view(frame, app, &theme, chunks[1]);
+            draw_windows_view(frame, app, &theme, chunks[1], hits);
         }
         View::Templates => {
-            draw_templates_view(frame, app, &theme, chunks[1]);
+            draw_templates_view(frame, app, &theme, chunks[1], hits);
+        }
+        View::Dashboard => {
+            dashboard::draw(frame, app, &theme, chunks[1], hits);
+        }
+        View::Grep => {
+            grep::draw(frame, app, &theme, chunks[1], hits);
         }
         View::Help => {
             // Help is drawn as overlay
         }
         View::Settings => {
             // Settings is drawn as overlay in mod.rs
-            draw_sessions_view(frame, app, &theme, chunks[1]);
+            draw_sessions_view(frame, app, &theme, chunks[1], hits);
         }
     }
 
     // Draw footer
-    draw_footer(frame, app, &theme, chunks[2]);
+    draw_footer(frame, app, &theme, chunks[2], hits);
 
     // Draw overlays
     if app.show_help {
@@ -62,17 +70,25 @@ pub fn draw(frame: &mut Frame, app: &App) {
     // Draw input dialogs
     match &app.input_mode {
         InputMode::Input { prompt, .. } => {
-            input::draw_input_dialog(frame, app, &theme, prompt);
+            input::draw_input_dialog(frame, app, &theme, prompt, hits);
         }
         InputMode::Confirm { message, .. } => {
-            input::draw_confirm_dialog(frame, app, &theme, message);
+            input::draw_confirm_dialog(frame, app, &theme, message, hits);
         }
         InputMode::Search => {
-            input::draw_search_bar(frame, app, &theme);
+            input::draw_search_bar(frame, &app.search_query, &theme);
+        }
+        InputMode::PreviewSearch => {
+            let query = app.preview_search.as_ref().map_or("", |s| s.query.as_str());
+            input::draw_search_bar(frame, query, &theme);
         }
         InputMode::Normal => {}
     }
 
+    if let Some(ref results) = app.bulk_report {
+        input::draw_bulk_report(frame, results, &theme, hits);
+    }
+
     // Draw status/error messages
     if let Some(ref msg) = app.error_message {
         draw_message(frame, &theme, msg, true);
@@ -82,10 +98,14 @@ pub fn draw(frame: &mut Frame, app: &App) {
 }
 
 /// Draw header
-fn draw_header(frame: &mut Frame, app: &App, theme: &Theme, area: Rect) {
+fn draw_header(frame: &mut Frame, app: &App, theme: &Theme, area: Rect, hits: &mut HitMap) {
     let title = match app.view {
         View::Sessions => {
-            let local_count = app.sessions.iter().filter(|s| s.host.is_none()).count();
+            let local_count = app
+                .sessions
+                .iter()
+                .filter(|s| s.host.is_none() && !s.is_virtual())
+                .count();
             let remote_count = app.sessions.iter().filter(|s| s.host.is_some()).count();
             let count_str = if remote_count > 0 {
                 format!("{} local, {} remote", local_count, remote_count)
@@ -104,64 +124,153 @@ fn draw_header(frame: &mut Frame, app: &App, theme: &Theme, area: Rect) {
         View::Templates => {
             format!(" sesh - Templates ({}) ", app.templates.len())
         }
+        View::Dashboard => format!(" sesh - Dashboard ({} tiles) ", app.dashboard.len()),
+        View::Grep => format!(" sesh - Grep ({} matches) ", app.grep_hits.len()),
         View::Help => " sesh - Help ".to_string(),
         View::Settings => " sesh - Settings ".to_string(),
     };
 
-    let help_hint = "[?] Help  [q] Quit";
+    let mut spans = vec![Span::styled(title, theme.title())];
+    let mut x = area.x + spans[0].width() as u16;
 
-    let title_len = title.len();
-    let help_len = help_hint.len();
-    let padding = area.width as usize - title_len - help_len;
+    // Host tabs when remote hosts are configured
+    if app.hosts.len() > 1 {
+        for (i, host) in app.hosts.iter().enumerate() {
+            let label = format!(" {} ", host.as_deref().unwrap_or("local"));
+            let width = label.chars().count() as u16;
+            let style = if i == app.host_index {
+                theme.selected()
+            } else {
+                theme.muted()
+            };
+            hits.push(
+                Rect::new(x, area.y, width.min(area.right().saturating_sub(x)), 1),
+                HitTarget::HostTab(i),
+            );
+            spans.push(Span::styled(label, style));
+            x += width;
+        }
+    }
 
-    let header = Line::from(vec![
-        Span::styled(title, theme.title()),
-        Span::raw(" ".repeat(padding.max(1))),
-        Span::styled(help_hint, theme.muted()),
-    ]);
+    let help_hints = [("[?]", "Help"), ("[q]", "Quit")];
+    let help_len: usize = help_hints
+        .iter()
+        .map(|(key, desc)| key.len() + desc.len() + 1)
+        .sum::<usize>()
+        + 2 * (help_hints.len() - 1);
+    let used: usize = spans.iter().map(|s| s.width()).sum();
+    let padding = (area.width as usize).saturating_sub(used + help_len).max(1);
+    spans.push(Span::raw(" ".repeat(padding)));
+
+    let origin = Rect {
+        x: area.x + (used + padding) as u16,
+        ..area
+    };
+    spans.extend(key_hints(&help_hints, theme, origin, HitTarget::Hint, hits));
 
-    frame.render_widget(Paragraph::new(header), area);
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
 }
 
 /// Draw sessions view (list + preview)
-fn draw_sessions_view(frame: &mut Frame, app: &App, theme: &Theme, area: Rect) {
-    if app.show_preview {
-        // Split into list and preview
-        let chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
-            .split(area);
-
-        sessions::draw(frame, app, theme, chunks[0]);
-        preview::draw(frame, app, theme, chunks[1]);
-    } else {
-        sessions::draw(frame, app, theme, area);
+fn draw_sessions_view(frame: &mut Frame, app: &App, theme: &Theme, area: Rect, hits: &mut HitMap) {
+    let (list, preview_area) = split_preview(app, area);
+
+    if let Some(list) = list {
+        sessions::draw(frame, app, theme, list, hits);
+    }
+    if let Some(preview_area) = preview_area {
+        preview::draw(frame, app, theme, preview_area, hits);
     }
 }
 
 /// Draw windows view
-fn draw_windows_view(frame: &mut Frame, app: &App, theme: &Theme, area: Rect) {
-    if app.show_preview {
-        let chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
-            .split(area);
-
-        windows::draw(frame, app, theme, chunks[0]);
-        preview::draw(frame, app, theme, chunks[1]);
-    } else {
-        windows::draw(frame, app, theme, area);
+fn draw_windows_view(frame: &mut Frame, app: &App, theme: &Theme, area: Rect, hits: &mut HitMap) {
+    let (list, preview_area) = split_preview(app, area);
+
+    if let Some(list) = list {
+        windows::draw(frame, app, theme, list, hits);
+    }
+    if let Some(preview_area) = preview_area {
+        preview::draw(frame, app, theme, preview_area, hits);
     }
 }
 
+/// Split the content area into list and preview panes
+///
+/// Honors the preview toggle, zoom, position and size settings. The bottom
+/// preview is never shorter than `ui.preview_lines`.
+fn split_preview(app: &App, area: Rect) -> (Option<Rect>, Option<Rect>) {
+    if !app.show_preview {
+        return (Some(area), None);
+    }
+    if app.preview_zoomed {
+        return (None, Some(area));
+    }
+
+    let ui = &app.config.ui;
+    let percent = ui.preview_size.clamp(10, 90);
+
+    let chunks = match ui.preview_position {
+        PreviewPosition::Bottom => {
+            let min_height = (ui.preview_lines as u16).saturating_add(2);
+            let height = ((u32::from(area.height) * u32::from(percent) / 100) as u16)
+                .max(min_height)
+                .min(area.height.saturating_sub(3));
+            Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(3), Constraint::Length(height)])
+                .split(area)
+        }
+        PreviewPosition::Right => Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(100 - percent),
+                Constraint::Percentage(percent),
+            ])
+            .split(area),
+    };
+
+    (Some(chunks[0]), Some(chunks[1]))
+}
+
 /// Draw templates view
-fn draw_templates_view(frame: &mut Frame, app: &App, theme: &Theme, area: Rect) {
-    templates::draw(frame, app, theme, area);
+fn draw_templates_view(frame: &mut Frame, app: &App, theme: &Theme, area: Rect, hits: &mut HitMap) {
+    templates::draw(frame, app, theme, area, hits);
 }
 
 /// Draw footer with key hints
-fn draw_footer(frame: &mut Frame, app: &App, theme: &Theme, area: Rect) {
+fn draw_footer(frame: &mut Frame, app: &App, theme: &Theme, area: Rect, hits: &mut HitMap) {
+    let preview_focused = app.focus == Focus::Preview
+        && app.show_preview
+        && matches!(app.view, View::Sessions | View::Windows);
+
     let keys = match app.view {
+        _ if preview_focused && app.copy_mode.is_some() => vec![
+            ("[h/j/k/l]", "Move"),
+            ("[w/b/e]", "Word"),
+            ("[v/V/C-v]", "Select"),
+            ("[y]", "Copy"),
+            ("[Y]", "To screen buffer"),
+            ("[Esc]", "Exit"),
+        ],
+        _ if preview_focused => vec![
+            ("[j/k]", "Scroll"),
+            ("[PgUp/PgDn]", "Page"),
+            ("[g/G]", "Top/Bottom"),
+            ("[/]", "Search"),
+            ("[v]", "Copy"),
+            (
+                "[f]",
+                if app.preview_follow {
+                    "Unfollow"
+                } else {
+                    "Follow"
+                },
+            ),
+            ("[Z]", if app.preview_zoomed { "Unzoom" } else { "Zoom" }),
+            ("[#]", "Line numbers"),
+            ("[Tab]", "List"),
+        ],
         View::Sessions => vec![
             ("[Enter]", "Attach"),
             ("[n]", "New"),
@@ -170,16 +279,37 @@ fn draw_footer(frame: &mut Frame, app: &App, theme: &Theme, area: Rect) {
             ("[w]", "Windows"),
             ("[t]", "Templates"),
             ("[/]", "Search"),
+            ("[s]", "Grep"),
+            ("[o/O]", "Sort/Group"),
+            ("[Space]", "Mark"),
             ("[r]", "Refresh"),
         ],
         View::Windows => vec![
             ("[Enter]", "Select"),
             ("[n]", "New"),
-            ("[r]", "Rename"),
+            ("[R]", "Rename"),
             ("[x]", "Kill"),
+            ("[</>]", "Move"),
+            ("[Space]", "Mark"),
+            ("[i]", "Send text"),
             ("[a]", "Attach"),
             ("[Esc]", "Back"),
         ],
+        View::Dashboard => vec![
+            ("[h/j/k/l]", "Move"),
+            (
+                "[Enter]",
+                if app.preview_zoomed { "Attach" } else { "Zoom" },
+            ),
+            ("[a]", "Attach"),
+            ("[Esc]", "Back"),
+        ],
+        View::Grep => vec![
+            ("[Enter]", "Open"),
+            ("[s]", "New search"),
+            ("[r]", "Search again"),
+            ("[Esc]", "Back"),
+        ],
         View::Templates => vec![("[Enter]", "Create"), ("[Esc]", "Back")],
         View::Help => vec![("[Esc]", "Close")],
         View::Settings => vec![
@@ -190,23 +320,52 @@ fn draw_footer(frame: &mut Frame, app: &App, theme: &Theme, area: Rect) {
         ],
     };
 
+    // Hints sit below the top border
+    let origin = Rect {
+        y: area.y + 1,
+        ..area
+    };
+    let spans = key_hints(&keys, theme, origin, HitTarget::Hint, hits);
+
+    let footer = Paragraph::new(Line::from(spans)).block(
+        Block::default()
+            .borders(Borders::TOP)
+            .border_style(theme.border()),
+    );
+
+    frame.render_widget(footer, area);
+}
+
+/// Build `[key] desc` hint spans starting at `origin`, recording a region per hint
+pub fn key_hints<'a>(
+    hints: &[(&'a str, &'a str)],
+    theme: &Theme,
+    origin: Rect,
+    target: fn(crossterm::event::KeyCode) -> HitTarget,
+    hits: &mut HitMap,
+) -> Vec<Span<'a>> {
     let mut spans = Vec::new();
-    for (i, (key, desc)) in keys.iter().enumerate() {
+    let mut x = origin.x;
+
+    for (i, (key, desc)) in hints.iter().enumerate() {
         if i > 0 {
             spans.push(Span::raw("  "));
+            x = x.saturating_add(2);
         }
+
+        let width = (key.chars().count() + 1 + desc.chars().count()) as u16;
+        if let Some(code) = hint_key(key) {
+            let width = width.min(origin.right().saturating_sub(x));
+            hits.push(Rect::new(x, origin.y, width, 1), target(code));
+        }
+        x = x.saturating_add(width);
+
         spans.push(Span::styled(*key, theme.key()));
         spans.push(Span::raw(" "));
         spans.push(Span::styled(*desc, theme.key_desc()));
     }
 
-    let footer = Paragraph::new(Line::from(spans)).block(
-        Block::default()
-            .borders(Borders::TOP)
-            .border_style(theme.border()),
-    );
-
-    frame.render_widget(footer, area);
+    spans
 }
 
 /// Draw status/error message
@@ -2,7 +2,10 @@
 //!
 //! Provides all TUI rendering functionality.
 
+mod dashboard;
+mod grep;
 mod help;
+pub mod hitmap;
 mod input;
 mod layout;
 mod preview;
@@ -10,22 +13,27 @@ mod sessions;
 pub mod settings;
 mod templates;
 pub mod theme;
+pub mod watch;
 mod windows;
 
 use ratatui::Frame;
 
 use crate::app::{App, View};
 
+use hitmap::HitMap;
+
 /// Main draw function
-pub fn draw(frame: &mut Frame, app: &App) {
+///
+/// Regions that react to the mouse are recorded in `hits`.
+pub fn draw(frame: &mut Frame, app: &App, hits: &mut HitMap) {
     match app.view {
         View::Settings => {
             // Draw main layout first, then overlay settings
-            layout::draw(frame, app);
+            layout::draw(frame, app, hits);
             let area = centered_rect(80, 80, frame.size());
             settings::draw(frame, app, &app.theme, area);
         }
-        _ => layout::draw(frame, app),
+        _ => layout::draw(frame, app, hits),
     }
 }
 
@@ -4,30 +4,60 @@
 
 use ratatui::{
     layout::Rect,
+    style::Style,
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph, Wrap},
     Frame,
 };
 
-use crate::app::App;
+use crate::app::{App, Focus};
 
+use super::hitmap::{HitMap, HitTarget};
 use super::theme::Theme;
 
 /// Draw the preview panel
-pub fn draw(frame: &mut Frame, app: &App, theme: &Theme, area: Rect) {
-    let title = if let Some(session) = get_preview_session_name(app) {
-        format!(" Preview - {} ", session)
-    } else {
-        " Preview ".to_string()
+pub fn draw(frame: &mut Frame, app: &App, theme: &Theme, area: Rect, hits: &mut HitMap) {
+    hits.push(area, HitTarget::Preview);
+
+    let focused = app.focus == Focus::Preview;
+    let inner_height = area.height.saturating_sub(2) as usize;
+
+    let mut title = match get_preview_session_name(app) {
+        Some(session) => format!(" Preview - {} ", session),
+        None => " Preview ".to_string(),
     };
+    if !app.preview.lines.is_empty() {
+        let last = (app.preview.scroll_offset + inner_height).min(app.preview.lines.len());
+        title.push_str(&format!(
+            "[{}-{}/{}] ",
+            app.preview.scroll_offset + 1,
+            last,
+            app.preview.lines.len()
+        ));
+    }
+    if app.preview_follow {
+        title.push_str("[follow] ");
+    }
+    if app.preview_zoomed {
+        title.push_str("[zoom] ");
+    }
+    if let Some(ref search) = app.preview_search {
+        title.push_str(&format!("[{}] ", search.status()));
+    }
+    if let Some(ref copy) = app.copy_mode {
+        let mode = copy.visual.map_or("COPY", |(_, kind)| kind.label());
+        title.push_str(&format!("[{}] ", mode));
+    }
 
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(theme.border())
+        .border_style(if focused {
+            theme.border_focused()
+        } else {
+            theme.border()
+        })
         .title(title);
 
-    let inner_height = area.height.saturating_sub(2) as usize;
-
     if app.preview.lines.is_empty() {
         let empty = Paragraph::new(vec![Line::from(Span::styled(
             "No preview available",
@@ -39,17 +69,37 @@ pub fn draw(frame: &mut Frame, app: &App, theme: &Theme, area: Rect) {
         return;
     }
 
-    // Get visible lines with line numbers
+    // Get visible lines, with line numbers if enabled
+    let line_numbers = app.config.ui.preview_line_numbers;
     let lines: Vec<Line> = app
         .preview
         .visible_lines(inner_height)
         .iter()
         .map(|(num, content)| {
-            Line::from(vec![
-                Span::styled(format!("{:>4}", num), theme.line_number()),
-                Span::styled("\u{2502} ", theme.muted()),
-                Span::styled((*content).to_string(), theme.preview_content()),
-            ])
+            // Lines that changed since the previous refresh stand out
+            let changed = app.preview.is_changed(num - 1);
+            let content_style = if changed {
+                theme.preview_changed()
+            } else {
+                theme.preview_content()
+            };
+            let number_style = if changed {
+                theme.preview_changed()
+            } else {
+                theme.line_number()
+            };
+
+            let mut spans = Vec::new();
+            if line_numbers {
+                spans.push(Span::styled(format!("{:>4}", num), number_style));
+                spans.push(Span::styled("\u{2502} ", theme.muted()));
+            }
+            if app.copy_mode.is_some() {
+                spans.extend(copy_spans(app, num - 1, content, content_style, theme));
+            } else {
+                spans.extend(content_spans(app, num - 1, content, content_style, theme));
+            }
+            Line::from(spans)
         })
         .collect();
 
@@ -60,15 +110,111 @@ pub fn draw(frame: &mut Frame, app: &App, theme: &Theme, area: Rect) {
     frame.render_widget(preview, area);
 }
 
+/// Split a line into spans, highlighting search matches
+fn content_spans<'a>(
+    app: &App,
+    index: usize,
+    content: &str,
+    style: Style,
+    theme: &Theme,
+) -> Vec<Span<'a>> {
+    let Some(ref search) = app.preview_search else {
+        return vec![Span::styled(content.to_string(), style)];
+    };
+
+    let mut spans = Vec::new();
+    let mut pos = 0;
+    for (i, m) in search.matches_on(index) {
+        if m.start < pos || m.end > content.len() {
+            continue;
+        }
+        if m.start > pos {
+            spans.push(Span::styled(content[pos..m.start].to_string(), style));
+        }
+        let match_style = if search.current == Some(i) {
+            theme.selected()
+        } else {
+            theme.search_match()
+        };
+        spans.push(Span::styled(
+            content[m.start..m.end].to_string(),
+            match_style,
+        ));
+        pos = m.end;
+    }
+    if pos < content.len() || spans.is_empty() {
+        spans.push(Span::styled(content[pos..].to_string(), style));
+    }
+
+    spans
+}
+
+/// Split a line into spans, highlighting the copy-mode selection and cursor
+fn copy_spans<'a>(
+    app: &App,
+    index: usize,
+    content: &str,
+    style: Style,
+    theme: &Theme,
+) -> Vec<Span<'a>> {
+    let Some(ref copy) = app.copy_mode else {
+        return vec![Span::styled(content.to_string(), style)];
+    };
+
+    let lines = &app.preview.lines;
+    let selected = copy.selected_columns(index, lines);
+    let cursor = (copy.line == index).then(|| copy.cursor_col(lines));
+
+    // Pad so the cursor shows on empty lines
+    let mut chars: Vec<char> = content.chars().collect();
+    if chars.is_empty() && cursor.is_some() {
+        chars.push(' ');
+    }
+
+    let style_at = |col: usize| {
+        if cursor == Some(col) {
+            theme.input_cursor()
+        } else if selected.is_some_and(|(from, to)| col >= from && col < to) {
+            theme.selected()
+        } else {
+            style
+        }
+    };
+
+    // Group runs of equally styled characters into spans
+    let mut spans: Vec<Span> = Vec::new();
+    let mut run = String::new();
+    let mut run_style = style_at(0);
+    for (col, c) in chars.iter().enumerate() {
+        let current = style_at(col);
+        if current != run_style && !run.is_empty() {
+            spans.push(Span::styled(std::mem::take(&mut run), run_style));
+        }
+        run_style = current;
+        run.push(*c);
+    }
+    spans.push(Span::styled(run, run_style));
+
+    spans
+}
+
 /// Get the name of the session being previewed
 fn get_preview_session_name(app: &App) -> Option<String> {
     match app.view {
-        crate::app::View::Sessions => app
-            .filtered_sessions
-            .get(app.session_index)
-            .and_then(|&idx| app.sessions.get(idx))
-            .map(|s| s.name.clone()),
-        crate::app::View::Windows => app.selected_session.clone(),
+        crate::app::View::Sessions => {
+            let session = app.get_selected_session()?;
+            Some(match app.preview_window_number() {
+                Some(number) => format!("{}:{}", session.name, number),
+                None => session.name.clone(),
+            })
+        }
+        crate::app::View::Windows => {
+            let session = app.selected_session.clone()?;
+            Some(match app.windows.get(app.window_index) {
+                Some(window) => format!("{}:{} {}", session, window.number, window.name),
+                None => session,
+            })
+        }
         _ => None,
     }
 }
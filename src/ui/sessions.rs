@@ -4,83 +4,60 @@
 
 use ratatui::{
     layout::Rect,
+    style::Style,
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, ListState},
     Frame,
 };
 
-use crate::app::App;
-use crate::screen::{Session, SessionStatus};
+use crate::app::{App, Focus, SessionRow};
+use crate::screen::bulk::Target;
+use crate::screen::{Session, SessionGroup, SessionStatus};
 
+use super::hitmap::HitMap;
 use super::theme::{Symbols, Theme};
 
 /// Draw the session list
-pub fn draw(frame: &mut Frame, app: &App, theme: &Theme, area: Rect) {
+pub fn draw(frame: &mut Frame, app: &App, theme: &Theme, area: Rect, hits: &mut HitMap) {
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(theme.border_focused())
-        .title(" Sessions ");
-
-    // Group sessions by host
-    let mut local_sessions: Vec<&Session> = Vec::new();
-    let mut remote_sessions: std::collections::HashMap<String, Vec<&Session>> =
-        std::collections::HashMap::new();
-
-    for idx in &app.filtered_sessions {
-        if let Some(session) = app.sessions.get(*idx) {
-            if let Some(ref host) = session.host {
-                remote_sessions
-                    .entry(host.clone())
-                    .or_default()
-                    .push(session);
-            } else {
-                local_sessions.push(session);
-            }
-        }
-    }
-
-    // Build list items
-    let mut items: Vec<ListItem> = Vec::new();
-    let mut item_to_session_idx: Vec<usize> = Vec::new();
-
-    // Local sessions header
-    if !local_sessions.is_empty() {
-        items.push(ListItem::new(Line::from(vec![
-            Span::styled(" LOCAL ", theme.header()),
-            Span::styled(
-                "\u{2500}".repeat(area.width.saturating_sub(10) as usize),
-                theme.muted(),
-            ),
-        ])));
-        item_to_session_idx.push(usize::MAX); // Header marker
-
-        for (i, session) in local_sessions.iter().enumerate() {
-            items.push(session_to_list_item(session, app, theme, area.width));
-            // Find the actual index
-            if let Some(pos) = app.sessions.iter().position(|s| s.id == session.id) {
-                item_to_session_idx.push(pos);
-            }
-        }
-    }
-
-    // Remote sessions by host
-    for (host, sessions) in &remote_sessions {
-        items.push(ListItem::new(Line::from(vec![
-            Span::styled(format!(" REMOTE ({}) ", host), theme.header()),
-            Span::styled(
-                "\u{2500}".repeat(area.width.saturating_sub(host.len() as u16 + 14) as usize),
-                theme.muted(),
-            ),
-        ])));
-        item_to_session_idx.push(usize::MAX); // Header marker
+        .border_style(if app.focus == Focus::List {
+            theme.border_focused()
+        } else {
+            theme.border()
+        })
+        .title(if app.marked.is_empty() {
+            format!(
+                " Sessions (sort: {}, group: {}) ",
+                app.config.ui.sort_by, app.config.ui.group_by
+            )
+        } else {
+            format!(
+                " Sessions (sort: {}, group: {}, {} marked) ",
+                app.config.ui.sort_by,
+                app.config.ui.group_by,
+                app.marked.len()
+            )
+        });
 
-        for session in sessions {
-            items.push(session_to_list_item(session, app, theme, area.width));
-            if let Some(pos) = app.sessions.iter().position(|s| s.id == session.id) {
-                item_to_session_idx.push(pos);
-            }
-        }
-    }
+    // Build list items from the grouped rows
+    let mut items: Vec<ListItem> = app
+        .session_rows
+        .iter()
+        .filter_map(|row| match row {
+            SessionRow::Header(group) => app
+                .session_groups
+                .get(*group)
+                .map(|group| group_header(group, app, theme, area.width)),
+            SessionRow::Session(idx) => app.sessions.get(*idx).map(|session| {
+                let highlights = app.search_highlights.get(idx).map(Vec::as_slice);
+                let target = Target::from_session(session);
+                let marked = app.marked.contains(&target);
+                let unread = app.unread.contains(&target);
+                session_to_list_item(session, highlights, marked, unread, theme, area.width)
+            }),
+        })
+        .collect();
 
     // Handle empty state
     if items.is_empty() {
@@ -91,13 +68,11 @@ pub fn draw(frame: &mut Frame, app: &App, theme: &Theme, area: Rect) {
         ])));
     }
 
-    // Calculate selected index in flat list
-    let mut selected = None;
-    if !app.filtered_sessions.is_empty() {
-        if let Some(session_idx) = app.filtered_sessions.get(app.session_index) {
-            selected = item_to_session_idx.iter().position(|&i| i == *session_idx);
-        }
-    }
+    let selected = if app.session_rows.is_empty() {
+        None
+    } else {
+        Some(app.session_index)
+    };
 
     let list = List::new(items)
         .block(block)
@@ -108,12 +83,37 @@ pub fn draw(frame: &mut Frame, app: &App, theme: &Theme, area: Rect) {
     state.select(selected);
 
     frame.render_stateful_widget(list, area, &mut state);
+    hits.push_list_rows(area, state.offset(), app.session_rows.len());
+}
+
+/// Build a group header line
+fn group_header<'a>(group: &SessionGroup, app: &App, theme: &Theme, width: u16) -> ListItem<'a> {
+    let collapsed = app.collapsed_groups.contains(&group.label);
+    let marker = if collapsed {
+        Symbols::COLLAPSED
+    } else {
+        Symbols::EXPANDED
+    };
+    let title = format!(
+        " {} {} ({}) ",
+        marker,
+        group.display_name(),
+        group.sessions.len()
+    );
+    let rule_width = (width as usize).saturating_sub(title.chars().count() + 4);
+
+    ListItem::new(Line::from(vec![
+        Span::styled(title, theme.header()),
+        Span::styled("\u{2500}".repeat(rule_width), theme.muted()),
+    ]))
 }
 
 /// Convert a session to a list item
 fn session_to_list_item<'a>(
     session: &'a Session,
-    _app: &App,
+    highlights: Option<&[usize]>,
+    marked: bool,
+    unread: bool,
     theme: &Theme,
     width: u16,
 ) -> ListItem<'a> {
@@ -122,6 +122,7 @@ fn session_to_list_item<'a>(
         SessionStatus::Detached => Span::styled(Symbols::DETACHED, theme.status_detached()),
         SessionStatus::Multi => Span::styled(Symbols::MULTI, theme.status_multi()),
         SessionStatus::Unknown => Span::styled("?", theme.muted()),
+        SessionStatus::Project => Span::styled(Symbols::PROJECT, theme.muted()),
     };
 
     let status_text = match session.status {
@@ -129,9 +130,14 @@ fn session_to_list_item<'a>(
         SessionStatus::Detached => Span::styled("detached", theme.status_detached()),
         SessionStatus::Multi => Span::styled("multi", theme.status_multi()),
         SessionStatus::Unknown => Span::styled("unknown", theme.muted()),
+        SessionStatus::Project => Span::styled("project", theme.muted()),
     };
 
-    let window_count = Span::styled(format!("{} win", session.window_count), theme.muted());
+    let window_count = if session.is_virtual() {
+        Span::styled(format!("{:<5}", "-"), theme.muted())
+    } else {
+        Span::styled(format!("{} win", session.window_count), theme.muted())
+    };
 
     let age = Span::styled(session.age_string(), theme.muted());
 
@@ -182,12 +188,28 @@ fn session_to_list_item<'a>(
     };
 
     // Build the line - session name uses full calculated width
-    let mut spans = vec![
-        Span::raw("  "),
-        status_symbol,
-        Span::raw(" "),
-        Span::styled(session.name.clone(), theme.normal()),
-    ];
+    let name_style = if session.is_virtual() {
+        theme.muted()
+    } else {
+        theme.normal()
+    };
+    let mark = if marked {
+        Span::styled(Symbols::MARKED, theme.accent())
+    } else {
+        Span::raw(" ")
+    };
+    let unread_marker = if unread {
+        Span::styled(Symbols::UNREAD, theme.preview_changed())
+    } else {
+        Span::raw(" ")
+    };
+    let mut spans = vec![mark, unread_marker, status_symbol, Span::raw(" ")];
+    spans.extend(highlight_name(
+        &session.name,
+        highlights.unwrap_or_default(),
+        name_style,
+        theme.search_match(),
+    ));
 
     // Add padding to align columns only if name is shorter than width
     let name_len = session.name.len();
@@ -203,12 +225,48 @@ fn session_to_list_item<'a>(
     spans.extend(dir_info);
     spans.extend(git_info);
 
-    spans.push(Span::raw("  "));
-    spans.push(age);
+    if !session.is_virtual() {
+        spans.push(Span::raw("  "));
+        spans.push(age);
+    }
 
     ListItem::new(Line::from(spans))
 }
 
+/// Split a name into spans, styling the matched character indices
+fn highlight_name<'a>(
+    name: &str,
+    indices: &[usize],
+    style: Style,
+    match_style: Style,
+) -> Vec<Span<'a>> {
+    let mut spans: Vec<Span> = Vec::new();
+    let mut current = String::new();
+    let mut current_matched = false;
+
+    for (i, c) in name.chars().enumerate() {
+        let matched = indices.contains(&i);
+        if matched != current_matched && !current.is_empty() {
+            let text = std::mem::take(&mut current);
+            spans.push(Span::styled(
+                text,
+                if current_matched { match_style } else { style },
+            ));
+        }
+        current_matched = matched;
+        current.push(c);
+    }
+
+    if !current.is_empty() {
+        spans.push(Span::styled(
+            current,
+            if current_matched { match_style } else { style },
+        ));
+    }
+
+    spans
+}
+
 /// Truncate a string to max length
 fn truncate_str(s: &str, max_len: usize) -> String {
     if s.len() <= max_len {
@@ -11,7 +11,9 @@ use ratatui::{
 };
 
 use crate::app::App;
+use crate::config::settings::PreviewPosition;
 use crate::config::Settings;
+use crate::screen::{GroupBy, SortKey};
 
 use super::theme::Theme;
 
@@ -104,10 +106,13 @@ pub fn get_settings_for_category(
             SettingItem {
                 key: "ui.theme".to_string(),
                 label: "Theme".to_string(),
-                value: SettingValue::Choice(
-                    settings.ui.theme.clone(),
-                    vec!["dark".to_string(), "light".to_string()],
-                ),
+                value: SettingValue::Choice(settings.ui.theme.clone(), {
+                    let mut themes = Theme::available(&settings.themes_dir());
+                    if !themes.contains(&settings.ui.theme) {
+                        themes.push(settings.ui.theme.clone());
+                    }
+                    themes
+                }),
                 description: "Color theme for the interface".to_string(),
             },
             SettingItem {
@@ -120,7 +125,55 @@ pub fn get_settings_for_category(
                 key: "ui.preview_lines".to_string(),
                 label: "Preview Lines".to_string(),
                 value: SettingValue::Number(settings.ui.preview_lines as i64),
-                description: "Number of lines in preview panel".to_string(),
+                description: "Minimum number of lines in preview panel".to_string(),
+            },
+            SettingItem {
+                key: "ui.preview_position".to_string(),
+                label: "Preview Position".to_string(),
+                value: SettingValue::Choice(
+                    settings.ui.preview_position.to_string(),
+                    PreviewPosition::all()
+                        .iter()
+                        .map(|p| p.to_string())
+                        .collect(),
+                ),
+                description: "Place the preview below or beside the list".to_string(),
+            },
+            SettingItem {
+                key: "ui.preview_size".to_string(),
+                label: "Preview Size".to_string(),
+                value: SettingValue::Number(settings.ui.preview_size as i64),
+                description: "Percentage of the screen used by the preview".to_string(),
+            },
+            SettingItem {
+                key: "ui.preview_line_numbers".to_string(),
+                label: "Line Numbers".to_string(),
+                value: SettingValue::Bool(settings.ui.preview_line_numbers),
+                description: "Show line numbers in the preview".to_string(),
+            },
+            SettingItem {
+                key: "ui.unread_markers".to_string(),
+                label: "Unread Markers".to_string(),
+                value: SettingValue::Bool(settings.ui.unread_markers),
+                description: "Mark sessions with output you haven't seen".to_string(),
+            },
+            SettingItem {
+                key: "ui.sort_by".to_string(),
+                label: "Sort By".to_string(),
+                value: SettingValue::Choice(
+                    settings.ui.sort_by.to_string(),
+                    SortKey::all().iter().map(|k| k.to_string()).collect(),
+                ),
+                description: "Session list order".to_string(),
+            },
+            SettingItem {
+                key: "ui.group_by".to_string(),
+                label: "Group By".to_string(),
+                value: SettingValue::Choice(
+                    settings.ui.group_by.to_string(),
+                    GroupBy::all().iter().map(|g| g.to_string()).collect(),
+                ),
+                description: "Session list grouping".to_string(),
             },
             SettingItem {
                 key: "ui.refresh_interval_ms".to_string(),
@@ -443,6 +496,45 @@ pub fn apply_setting(settings: &mut Settings, key: &str, value: &SettingValue) {
                 settings.ui.preview_lines = *v as usize;
             }
         }
+        "ui.preview_position" => {
+            if let SettingValue::Choice(v, _) = value {
+                if let Some(pos) = PreviewPosition::all()
+                    .into_iter()
+                    .find(|p| p.to_string() == *v)
+                {
+                    settings.ui.preview_position = pos;
+                }
+            }
+        }
+        "ui.preview_size" => {
+            if let SettingValue::Number(v) = value {
+                settings.ui.preview_size = (*v).clamp(10, 90) as u16;
+            }
+        }
+        "ui.preview_line_numbers" => {
+            if let SettingValue::Bool(v) = value {
+                settings.ui.preview_line_numbers = *v;
+            }
+        }
+        "ui.unread_markers" => {
+            if let SettingValue::Bool(v) = value {
+                settings.ui.unread_markers = *v;
+            }
+        }
+        "ui.sort_by" => {
+            if let SettingValue::Choice(v, _) = value {
+                if let Some(key) = SortKey::all().into_iter().find(|k| k.to_string() == *v) {
+                    settings.ui.sort_by = key;
+                }
+            }
+        }
+        "ui.group_by" => {
+            if let SettingValue::Choice(v, _) = value {
+                if let Some(group) = GroupBy::all().into_iter().find(|g| g.to_string() == *v) {
+                    settings.ui.group_by = group;
+                }
+            }
+        }
         "ui.refresh_interval_ms" => {
             if let SettingValue::Number(v) = value {
                 settings.ui.refresh_interval_ms = *v as u32;
@@ -11,22 +11,23 @@ use ratatui::{
 
 use crate::app::App;
 
+use super::hitmap::HitMap;
 use super::theme::Theme;
 
 /// Draw the templates view
-pub fn draw(frame: &mut Frame, app: &App, theme: &Theme, area: Rect) {
+pub fn draw(frame: &mut Frame, app: &App, theme: &Theme, area: Rect, hits: &mut HitMap) {
     // Split into list and preview
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
         .split(area);
 
-    draw_template_list(frame, app, theme, chunks[0]);
+    draw_template_list(frame, app, theme, chunks[0], hits);
     draw_template_preview(frame, app, theme, chunks[1]);
 }
 
 /// Draw the template list
-fn draw_template_list(frame: &mut Frame, app: &App, theme: &Theme, area: Rect) {
+fn draw_template_list(frame: &mut Frame, app: &App, theme: &Theme, area: Rect, hits: &mut HitMap) {
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(theme.border_focused())
@@ -78,6 +79,7 @@ fn draw_template_list(frame: &mut Frame, app: &App, theme: &Theme, area: Rect) {
     state.select(Some(app.template_index));
 
     frame.render_stateful_widget(list, area, &mut state);
+    hits.push_list_rows(area, state.offset(), app.templates.len());
 }
 
 /// Draw template preview/details
@@ -138,6 +140,18 @@ fn draw_template_preview(frame: &mut Frame, app: &App, theme: &Theme, area: Rect
             }
         }
 
+        if !template.warnings.is_empty() {
+            lines.push(Line::from(Span::raw("")));
+            lines.push(Line::from(Span::styled("Warnings:", theme.warning())));
+
+            for warning in &template.warnings {
+                lines.push(Line::from(vec![
+                    Span::raw("  "),
+                    Span::styled(warning, theme.warning()),
+                ]));
+            }
+        }
+
         let preview = Paragraph::new(lines).block(block);
         frame.render_widget(preview, area);
     } else {
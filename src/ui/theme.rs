@@ -2,7 +2,21 @@
 //!
 //! Defines colors, styles, and visual elements for the TUI.
 
+use anyhow::{bail, Context, Result};
 use ratatui::style::{Color, Modifier, Style};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::config::Settings;
+use crate::screen::WindowActivity;
+
+/// Built-in theme names
+pub const BUILTIN_THEMES: [&str; 2] = ["dark", "light"];
+
+/// Maximum depth of `base` inheritance between theme files
+const MAX_THEME_DEPTH: usize = 8;
 
 /// Theme configuration
 #[derive(Debug, Clone)]
@@ -29,6 +43,8 @@ pub struct Theme {
     pub selected_bg: Color,
     /// Selected item foreground
     pub selected_fg: Color,
+    /// Background of input fields and overlays
+    pub overlay_bg: Color,
 }
 
 impl Default for Theme {
@@ -52,6 +68,7 @@ impl Theme {
             border: Color::DarkGray,
             selected_bg: Color::Rgb(40, 44, 52),
             selected_fg: Color::Cyan,
+            overlay_bg: Color::Rgb(20, 20, 20),
         }
     }
 
@@ -69,7 +86,183 @@ impl Theme {
             border: Color::Gray,
             selected_bg: Color::Rgb(230, 240, 255),
             selected_fg: Color::Blue,
+            overlay_bg: Color::Rgb(245, 245, 245),
+        }
+    }
+
+    /// Theme without colors, used when `NO_COLOR` is set
+    pub fn no_color() -> Self {
+        Self {
+            fg: Color::Reset,
+            bg: Color::Reset,
+            accent: Color::Reset,
+            accent_secondary: Color::Reset,
+            success: Color::Reset,
+            warning: Color::Reset,
+            error: Color::Reset,
+            muted: Color::Reset,
+            border: Color::Reset,
+            selected_bg: Color::Reset,
+            selected_fg: Color::Reset,
+            overlay_bg: Color::Reset,
+        }
+    }
+
+    /// Get a built-in theme by name
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            _ => None,
+        }
+    }
+
+    /// Load the theme selected in the settings
+    ///
+    /// Returns the colorless theme when `NO_COLOR` is set.
+    pub fn from_settings(settings: &Settings) -> Result<Self> {
+        if no_color_requested() {
+            return Ok(Self::no_color());
+        }
+
+        Self::load(&settings.ui.theme, &settings.themes_dir())
+    }
+
+    /// Load a theme by name: a built-in theme or a file in `dir`
+    ///
+    /// Files are looked up as `<name>.toml` (sesh theme), `<name>.yaml` or
+    /// `<name>.yml` (base16 scheme) and `<name>.conf` (terminal palette).
+    pub fn load(name: &str, dir: &Path) -> Result<Self> {
+        Self::load_with_depth(name, dir, 0)
+    }
+
+    fn load_with_depth(name: &str, dir: &Path, depth: usize) -> Result<Self> {
+        if let Some(theme) = Self::builtin(name) {
+            return Ok(theme);
+        }
+
+        if depth > MAX_THEME_DEPTH {
+            bail!("Theme '{}' inherits too deeply (circular base?)", name);
+        }
+
+        let path = find_theme_file(name, dir)
+            .ok_or_else(|| anyhow::anyhow!("Theme '{}' not found in {:?}", name, dir))?;
+        let content = std::fs::read_to_string(&path)
+            .context(format!("Failed to read theme file: {:?}", path))?;
+
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("toml") => {
+                let file: ThemeFile = toml::from_str(&content)
+                    .context(format!("Failed to parse theme file: {:?}", path))?;
+                file.apply(dir, depth)
+            }
+            Some("yaml") | Some("yml") => Self::from_base16(&content),
+            _ => Self::from_terminal_palette(&content),
         }
+        .context(format!("Invalid theme '{}'", name))
+    }
+
+    /// List built-in themes followed by theme files in `dir`
+    pub fn available(dir: &Path) -> Vec<String> {
+        let mut names: Vec<String> = BUILTIN_THEMES.iter().map(|s| s.to_string()).collect();
+
+        let mut files: Vec<String> = std::fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                matches!(
+                    path.extension().and_then(|e| e.to_str()),
+                    Some("toml" | "yaml" | "yml" | "conf")
+                )
+            })
+            .filter_map(|path| Some(path.file_stem()?.to_string_lossy().into_owned()))
+            .filter(|name| !names.contains(name))
+            .collect();
+        files.sort();
+        files.dedup();
+
+        names.extend(files);
+        names
+    }
+
+    /// Build a theme from a base16 scheme (YAML with `base00`..`base0F`)
+    pub fn from_base16(content: &str) -> Result<Self> {
+        let scheme: HashMap<String, serde_yaml::Value> =
+            serde_yaml::from_str(content).context("Failed to parse base16 scheme")?;
+
+        let color = |key: &str| -> Result<Color> {
+            let value = scheme
+                .iter()
+                .find(|(k, _)| k.eq_ignore_ascii_case(key))
+                .and_then(|(_, v)| v.as_str())
+                .ok_or_else(|| anyhow::anyhow!("base16 scheme is missing {}", key))?;
+            parse_color(value)
+        };
+
+        Ok(Self {
+            fg: color("base05")?,
+            bg: color("base00")?,
+            accent: color("base0C")?,
+            accent_secondary: color("base0D")?,
+            success: color("base0B")?,
+            warning: color("base0A")?,
+            error: color("base08")?,
+            muted: color("base03")?,
+            border: color("base03")?,
+            selected_bg: color("base02")?,
+            selected_fg: color("base0C")?,
+            overlay_bg: color("base01")?,
+        })
+    }
+
+    /// Build a theme from a terminal palette
+    ///
+    /// Accepts `key value` lines as used by kitty (`foreground #c0c0c0`,
+    /// `color4 #0000ff`) and Xresources (`*.color4: #0000ff`).
+    pub fn from_terminal_palette(content: &str) -> Result<Self> {
+        let mut palette: HashMap<String, Color> = HashMap::new();
+
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with('!') {
+                continue;
+            }
+
+            let (key, value) = match line.split_once(|c: char| c == ':' || c.is_whitespace()) {
+                Some((key, value)) => (key, value.trim()),
+                None => continue,
+            };
+            let key = key.trim_start_matches('*').trim_start_matches('.');
+            let key = key.rsplit('.').next().unwrap_or(key).to_lowercase();
+
+            if let Ok(color) = parse_color(value) {
+                palette.insert(key, color);
+            }
+        }
+
+        let color = |key: &str| -> Result<Color> {
+            palette
+                .get(key)
+                .copied()
+                .ok_or_else(|| anyhow::anyhow!("terminal palette is missing {}", key))
+        };
+
+        Ok(Self {
+            fg: color("foreground")?,
+            bg: color("background")?,
+            accent: color("color6")?,
+            accent_secondary: color("color4")?,
+            success: color("color2")?,
+            warning: color("color3")?,
+            error: color("color1")?,
+            muted: color("color8")?,
+            border: color("color8")?,
+            selected_bg: color("selection_background").or_else(|_| color("color0"))?,
+            selected_fg: color("color6")?,
+            overlay_bg: color("color0")?,
+        })
     }
 
     // Style methods
@@ -112,11 +305,19 @@ impl Theme {
     }
 
     /// Selected item style
+    ///
+    /// Without a selection background (e.g. `NO_COLOR`) the item is reversed.
     pub fn selected(&self) -> Style {
-        Style::default()
+        let style = Style::default()
             .fg(self.selected_fg)
             .bg(self.selected_bg)
-            .add_modifier(Modifier::BOLD)
+            .add_modifier(Modifier::BOLD);
+
+        if self.selected_bg == Color::Reset {
+            style.add_modifier(Modifier::REVERSED)
+        } else {
+            style
+        }
     }
 
     /// Unselected item style
@@ -139,6 +340,21 @@ impl Theme {
         Style::default().fg(self.error)
     }
 
+    /// Preview line that changed since the previous refresh
+    pub fn preview_changed(&self) -> Style {
+        Style::default().fg(self.success)
+    }
+
+    /// Window activity (idle windows use the border color)
+    pub fn activity(&self, activity: WindowActivity) -> Style {
+        match activity {
+            WindowActivity::Active => self.success(),
+            WindowActivity::Bell => self.warning(),
+            WindowActivity::Running => self.accent(),
+            WindowActivity::Idle => self.border(),
+        }
+    }
+
     /// Status: attached
     pub fn status_attached(&self) -> Style {
         Style::default().fg(self.success)
@@ -193,7 +409,7 @@ impl Theme {
 
     /// Input field style
     pub fn input(&self) -> Style {
-        Style::default().fg(self.fg).bg(Color::Rgb(30, 30, 30))
+        Style::default().fg(self.fg).bg(self.overlay_bg)
     }
 
     /// Input cursor style
@@ -208,12 +424,115 @@ impl Theme {
             .add_modifier(Modifier::BOLD)
     }
 
+    /// Search match highlight
+    pub fn search_match(&self) -> Style {
+        Style::default()
+            .fg(self.warning)
+            .add_modifier(Modifier::BOLD)
+    }
+
     /// Help overlay style
     pub fn help_overlay(&self) -> Style {
-        Style::default().fg(self.fg).bg(Color::Rgb(20, 20, 20))
+        Style::default().fg(self.fg).bg(self.overlay_bg)
+    }
+}
+
+/// A sesh theme file (`~/.config/sesh/themes/<name>.toml`)
+///
+/// Every color is optional and overrides the base theme.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ThemeFile {
+    /// Theme to start from (built-in or another theme file, default "dark")
+    pub base: Option<String>,
+    /// base16 scheme or terminal palette to import, relative to the themes directory
+    pub palette: Option<String>,
+    pub fg: Option<String>,
+    pub bg: Option<String>,
+    pub accent: Option<String>,
+    pub accent_secondary: Option<String>,
+    pub success: Option<String>,
+    pub warning: Option<String>,
+    pub error: Option<String>,
+    pub muted: Option<String>,
+    pub border: Option<String>,
+    pub selected_bg: Option<String>,
+    pub selected_fg: Option<String>,
+    pub overlay_bg: Option<String>,
+}
+
+impl ThemeFile {
+    /// Resolve the base theme and apply the overrides
+    fn apply(&self, dir: &Path, depth: usize) -> Result<Theme> {
+        let mut theme = match &self.palette {
+            Some(palette) => {
+                let path = dir.join(shellexpand::tilde(palette).as_ref());
+                let content = std::fs::read_to_string(&path)
+                    .context(format!("Failed to read palette: {:?}", path))?;
+                match path.extension().and_then(|e| e.to_str()) {
+                    Some("yaml") | Some("yml") => Theme::from_base16(&content)?,
+                    _ => Theme::from_terminal_palette(&content)?,
+                }
+            }
+            None => Theme::load_with_depth(self.base.as_deref().unwrap_or("dark"), dir, depth + 1)?,
+        };
+
+        let overrides = [
+            (&self.fg, &mut theme.fg),
+            (&self.bg, &mut theme.bg),
+            (&self.accent, &mut theme.accent),
+            (&self.accent_secondary, &mut theme.accent_secondary),
+            (&self.success, &mut theme.success),
+            (&self.warning, &mut theme.warning),
+            (&self.error, &mut theme.error),
+            (&self.muted, &mut theme.muted),
+            (&self.border, &mut theme.border),
+            (&self.selected_bg, &mut theme.selected_bg),
+            (&self.selected_fg, &mut theme.selected_fg),
+            (&self.overlay_bg, &mut theme.overlay_bg),
+        ];
+        for (value, color) in overrides {
+            if let Some(value) = value {
+                *color = parse_color(value)?;
+            }
+        }
+
+        Ok(theme)
     }
 }
 
+/// Check whether colors are disabled via `NO_COLOR` (https://no-color.org)
+pub fn no_color_requested() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty())
+}
+
+/// Find a theme file by name (or path) in the themes directory
+fn find_theme_file(name: &str, dir: &Path) -> Option<PathBuf> {
+    let direct = PathBuf::from(shellexpand::tilde(name).as_ref());
+    if direct.extension().is_some() && direct.is_file() {
+        return Some(direct);
+    }
+
+    ["toml", "yaml", "yml", "conf"]
+        .iter()
+        .map(|ext| dir.join(format!("{}.{}", name, ext)))
+        .find(|path| path.is_file())
+}
+
+/// Parse a color name, ANSI index, `#rrggbb` or bare `rrggbb` value
+fn parse_color(value: &str) -> Result<Color> {
+    let value = value.trim().trim_matches('"').trim_matches('\'');
+    let is_bare_hex = value.len() == 6 && value.chars().all(|c| c.is_ascii_hexdigit());
+
+    let parsed = if is_bare_hex {
+        Color::from_str(&format!("#{}", value))
+    } else {
+        Color::from_str(value)
+    };
+
+    parsed.map_err(|_| anyhow::anyhow!("Invalid color '{}'", value))
+}
+
 /// Symbols used in the UI
 pub struct Symbols;
 
@@ -224,6 +543,16 @@ impl Symbols {
     pub const DETACHED: &'static str = "\u{25CB}"; // ○
     /// Multi-user session indicator
     pub const MULTI: &'static str = "\u{25C9}"; // ◉
+    /// Discovered project without a session
+    pub const PROJECT: &'static str = "\u{25CC}"; // ◌
+    /// Session marked for a bulk operation
+    pub const MARKED: &'static str = "*";
+    /// Session with output the user hasn't seen
+    pub const UNREAD: &'static str = "\u{2022}"; // •
+    /// Expanded group header
+    pub const EXPANDED: &'static str = "\u{25BE}"; // ▾
+    /// Collapsed group header
+    pub const COLLAPSED: &'static str = "\u{25B8}"; // ▸
     /// Selection cursor
     pub const CURSOR: &'static str = ">";
     /// Git clean indicator
@@ -276,6 +605,68 @@ mod tests {
         assert_eq!(format_session_count(3, 2), "3 local, 2 remote");
     }
 
+    #[test]
+    fn test_theme_file_overrides_base() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("mine.toml"),
+            "base = \"light\"\naccent = \"#ff8800\"\nmuted = \"8\"\n",
+        )
+        .unwrap();
+
+        let theme = Theme::load("mine", dir.path()).unwrap();
+        assert_eq!(theme.accent, Color::Rgb(255, 136, 0));
+        assert_eq!(theme.muted, Color::Indexed(8));
+        assert_eq!(theme.fg, Theme::light().fg);
+    }
+
+    #[test]
+    fn test_base16_scheme() {
+        let dir = tempfile::tempdir().unwrap();
+        let scheme: String = (0..16)
+            .map(|i| format!("base{:02X}: \"{:02x}0000\"\n", i, i * 16))
+            .collect();
+        std::fs::write(dir.path().join("red.yaml"), scheme).unwrap();
+
+        let theme = Theme::load("red", dir.path()).unwrap();
+        assert_eq!(theme.bg, Color::Rgb(0x00, 0, 0));
+        assert_eq!(theme.fg, Color::Rgb(0x50, 0, 0));
+        assert_eq!(theme.error, Color::Rgb(0x80, 0, 0));
+    }
+
+    #[test]
+    fn test_terminal_palette_via_theme_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut palette = String::from("foreground #eeeeee\n*.background: #111111\n");
+        for i in 0..16 {
+            palette.push_str(&format!("color{} #0000{:02x}\n", i, i));
+        }
+        std::fs::write(dir.path().join("term.conf"), palette).unwrap();
+        std::fs::write(
+            dir.path().join("custom.toml"),
+            "palette = \"term.conf\"\nerror = \"red\"\n",
+        )
+        .unwrap();
+
+        let theme = Theme::load("custom", dir.path()).unwrap();
+        assert_eq!(theme.fg, Color::Rgb(0xee, 0xee, 0xee));
+        assert_eq!(theme.bg, Color::Rgb(0x11, 0x11, 0x11));
+        assert_eq!(theme.accent, Color::Rgb(0, 0, 6));
+        assert_eq!(theme.error, Color::Red);
+
+        let names = Theme::available(dir.path());
+        assert_eq!(names, vec!["dark", "light", "custom", "term"]);
+    }
+
+    #[test]
+    fn test_missing_and_circular_themes() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(Theme::load("nope", dir.path()).is_err());
+
+        std::fs::write(dir.path().join("loop.toml"), "base = \"loop\"\n").unwrap();
+        assert!(Theme::load("loop", dir.path()).is_err());
+    }
+
     #[test]
     fn test_format_window_count() {
         assert_eq!(format_window_count(1), "1 win");
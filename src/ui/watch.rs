@@ -0,0 +1,55 @@
+//! Watch screen
+//!
+//! Full-terminal, read-only view of one window for `sesh watch`.
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::screen::Preview;
+
+use super::dashboard::tail_range;
+use super::theme::Theme;
+
+/// Draw the newest lines of a window under a one-line title
+///
+/// Lines that changed since the previous capture are highlighted.
+pub fn draw(frame: &mut Frame, title: &str, preview: &Preview, error: Option<&str>, theme: &Theme) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(1)])
+        .split(frame.size());
+
+    let hint = "  [q] Quit";
+    let header = Line::from(vec![
+        Span::styled(title.to_string(), theme.title()),
+        Span::styled(hint, theme.muted()),
+    ]);
+    frame.render_widget(Paragraph::new(header), chunks[0]);
+
+    let block = Block::default()
+        .borders(Borders::TOP)
+        .border_style(theme.border());
+    let height = chunks[1].height.saturating_sub(1) as usize;
+
+    let lines: Vec<Line> = if let Some(error) = error {
+        vec![Line::from(Span::styled(error.to_string(), theme.error()))]
+    } else {
+        tail_range(&preview.lines, height)
+            .map(|i| {
+                let line = &preview.lines[i];
+                let style = if preview.is_changed(i) {
+                    theme.preview_changed()
+                } else {
+                    theme.preview_content()
+                };
+                Line::from(Span::styled(line.clone(), style))
+            })
+            .collect()
+    };
+
+    frame.render_widget(Paragraph::new(lines).block(block), chunks[1]);
+}
@@ -9,22 +9,27 @@ use ratatui::{
     Frame,
 };
 
-use crate::app::App;
+use crate::app::{App, Focus};
 use crate::screen::{Window, WindowActivity};
 
-use super::theme::Theme;
+use super::hitmap::HitMap;
+use super::theme::{Symbols, Theme};
 
 /// Draw the windows list
-pub fn draw(frame: &mut Frame, app: &App, theme: &Theme, area: Rect) {
-    let title = if let Some(ref session) = app.selected_session {
-        format!(" Windows - {} ", session)
-    } else {
-        " Windows ".to_string()
+pub fn draw(frame: &mut Frame, app: &App, theme: &Theme, area: Rect, hits: &mut HitMap) {
+    let title = match (&app.selected_session, app.marked_windows.len()) {
+        (Some(session), 0) => format!(" Windows - {} ", session),
+        (Some(session), marked) => format!(" Windows - {} ({} marked) ", session, marked),
+        (None, _) => " Windows ".to_string(),
     };
 
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(theme.border_focused())
+        .border_style(if app.focus == Focus::List {
+            theme.border_focused()
+        } else {
+            theme.border()
+        })
         .title(title);
 
     if app.windows.is_empty() {
@@ -42,7 +47,10 @@ pub fn draw(frame: &mut Frame, app: &App, theme: &Theme, area: Rect) {
     let items: Vec<ListItem> = app
         .windows
         .iter()
-        .map(|window| window_to_list_item(window, theme, area.width))
+        .map(|window| {
+            let marked = app.marked_windows.contains(&window.number);
+            window_to_list_item(window, marked, theme, area.width)
+        })
         .collect();
 
     let list = List::new(items)
@@ -54,10 +62,16 @@ pub fn draw(frame: &mut Frame, app: &App, theme: &Theme, area: Rect) {
     state.select(Some(app.window_index));
 
     frame.render_stateful_widget(list, area, &mut state);
+    hits.push_list_rows(area, state.offset(), app.windows.len());
 }
 
 /// Convert a window to a list item
-fn window_to_list_item<'a>(window: &'a Window, theme: &Theme, _width: u16) -> ListItem<'a> {
+fn window_to_list_item<'a>(
+    window: &'a Window,
+    marked: bool,
+    theme: &Theme,
+    _width: u16,
+) -> ListItem<'a> {
     let number_str = format!("{:>2}", window.number);
     let number = Span::styled(number_str, theme.muted());
 
@@ -77,10 +91,8 @@ fn window_to_list_item<'a>(window: &'a Window, theme: &Theme, _width: u16) -> Li
     };
 
     let activity = match window.activity {
-        WindowActivity::Active => Span::styled("active", theme.success()),
-        WindowActivity::Bell => Span::styled("bell", theme.warning()),
-        WindowActivity::Running => Span::styled("running", theme.accent()),
         WindowActivity::Idle => Span::styled("idle", theme.muted()),
+        activity => Span::styled(activity.to_string(), theme.activity(activity)),
     };
 
     let flags = if window.active {
@@ -91,8 +103,15 @@ fn window_to_list_item<'a>(window: &'a Window, theme: &Theme, _width: u16) -> Li
         Span::raw(" ")
     };
 
+    let mark = if marked {
+        Span::styled(Symbols::MARKED, theme.accent())
+    } else {
+        Span::raw(" ")
+    };
+
     ListItem::new(Line::from(vec![
-        Span::raw("  "),
+        mark,
+        Span::raw(" "),
         number,
         Span::raw(": "),
         name,
@@ -185,7 +185,7 @@ mod shell_integration {
 }
 
 mod preview_tests {
-    use sesh::screen::types::Preview;
+    use sesh::screen::types::{changed_lines, Preview};
 
     #[test]
     fn test_preview_empty() {
@@ -208,6 +208,7 @@ mod preview_tests {
             total_lines: 5,
             scroll_offset: 0,
             updating: false,
+            changed: Vec::new(),
         };
 
         let visible = preview.visible_lines(3);
@@ -224,6 +225,7 @@ mod preview_tests {
             total_lines: 20,
             scroll_offset: 0,
             updating: false,
+            changed: Vec::new(),
         };
 
         // Scroll down
@@ -238,4 +240,56 @@ mod preview_tests {
         let visible = preview.visible_lines(5);
         assert_eq!(visible[0], (4, "Line 4"));
     }
+
+    fn lines(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_changed_lines_appended_output() {
+        let previous = lines(&["$ make", "building", ""]);
+        let current = lines(&["$ make", "building", "done", ""]);
+
+        assert_eq!(
+            changed_lines(&previous, &current),
+            vec![false, false, true, true]
+        );
+        assert_eq!(changed_lines(&[], &current), vec![false; 4]);
+    }
+
+    #[test]
+    fn test_changed_lines_scrolled_history() {
+        // The oldest line dropped off a full scrollback
+        let previous = lines(&["one", "two", "three", "four"]);
+        let current = lines(&["two", "three", "four", "five"]);
+
+        assert_eq!(
+            changed_lines(&previous, &current),
+            vec![false, false, false, true]
+        );
+    }
+
+    #[test]
+    fn test_preview_diff_and_fingerprint() {
+        let previous = Preview {
+            lines: lines(&["a", "b"]),
+            ..Preview::default()
+        };
+        let mut current = Preview {
+            lines: lines(&["a", "c", ""]),
+            ..Preview::default()
+        };
+        current.diff_against(&previous);
+
+        assert!(!current.is_changed(0));
+        assert!(current.is_changed(1));
+        assert!(!current.is_changed(10));
+
+        let trimmed = Preview {
+            lines: lines(&["a", "c"]),
+            ..Preview::default()
+        };
+        assert_eq!(current.fingerprint(), trimmed.fingerprint());
+        assert_ne!(previous.fingerprint(), trimmed.fingerprint());
+    }
 }